@@ -1,18 +1,15 @@
-use std::fs::File;
-use std::io::prelude::*;
-
-pub fn run() {
-    let mut f = File::open("src/day_01/a.input").expect("File not found");
-
-    let mut contents = String::new();
-    f.read_to_string(&mut contents)
-        .expect("Something went wrong reading the file");
-
-    let val: i32 = contents
-      .split("\n")
-      .filter(|row| !row.is_empty())
-      .map(|row| row.parse::<i32>().expect("Value not number?"))
-      .sum();
-
-    println!("Value: {}", val);
-}
\ No newline at end of file
+use error::ParseError;
+use text;
+
+pub fn parse(contents: &str) -> Result<Vec<i32>, ParseError> {
+  text::lines(contents)
+    .map(|(line, row)| {
+      row
+        .parse::<i32>()
+        .map_err(|_| ParseError::new(1, line, row, 0, "expected a frequency change like \"+7\""))
+    }).collect()
+}
+
+pub fn resulting_frequency(changes: &[i32]) -> i32 {
+  changes.iter().sum()
+}
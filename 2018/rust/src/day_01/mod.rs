@@ -1,2 +1,25 @@
 pub mod a;
-pub mod b;
\ No newline at end of file
+pub mod b;
+
+use aoc_macros::aoc;
+use error::ParseError;
+use solution::{Answer, Solution};
+
+pub struct Day01;
+
+#[aoc(day = 1)]
+impl Solution for Day01 {
+  type Input<'a> = Vec<i32>;
+
+  fn parse(input: &str) -> Result<Vec<i32>, ParseError> {
+    a::parse(input)
+  }
+
+  fn part_a(changes: &Vec<i32>) -> Answer {
+    a::resulting_frequency(changes).into()
+  }
+
+  fn part_b(changes: &Vec<i32>) -> Answer {
+    b::first_repeated_frequency(changes).into()
+  }
+}
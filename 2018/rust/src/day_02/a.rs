@@ -1,6 +1,6 @@
+use error::ParseError;
 use std::collections::BTreeMap;
-use std::fs::File;
-use std::io::prelude::*;
+use text;
 
 fn check_row(row: &str) -> (bool, bool) {
   let mut heatmap = BTreeMap::new();
@@ -24,25 +24,24 @@ fn bool_to_int(val: bool) -> i32 {
   }
 }
 
-pub fn run() {
-    let mut f = File::open("src/day_02/a.input").expect("File not found");
-
-    let mut contents = String::new();
-    f.read_to_string(&mut contents)
-        .expect("Something went wrong reading the file");
+pub fn parse(contents: &str) -> Result<Vec<&str>, ParseError> {
+  text::lines(contents)
+    .map(|(line, row)| match row.find(|c: char| !c.is_ascii_lowercase()) {
+      Some(offset) => Err(ParseError::new(2, line, row, offset, "expected a lowercase letter")),
+      None => Ok(row),
+    }).collect()
+}
 
-    let (num_twins, num_triplets) = contents
-      .split("\n")
-      .filter(|row| !row.is_empty())
-      .fold((0, 0), |(num_twins, num_triplets), row| {
-        let (has_twins, has_triplets) = check_row(row);
-        let twin_diff = bool_to_int(has_twins);
-        let triplet_diff = bool_to_int(has_triplets);
+pub fn checksum(ids: &[&str]) -> i32 {
+  let (num_twins, num_triplets) = ids.iter().fold((0, 0), |(num_twins, num_triplets), row| {
+    let (has_twins, has_triplets) = check_row(row);
+    let twin_diff = bool_to_int(has_twins);
+    let triplet_diff = bool_to_int(has_triplets);
 
-        (num_twins + twin_diff, num_triplets + triplet_diff)
-      });
+    (num_twins + twin_diff, num_triplets + triplet_diff)
+  });
 
-    println!("Value: {}", num_twins * num_triplets);
+  num_twins * num_triplets
 }
 
 #[cfg(test)]
@@ -1,6 +1,3 @@
-use std::fs::File;
-use std::io::prelude::*;
-
 fn check_row(row1: &str, row2: &str) -> i32 {
   row1.chars().zip(row2.chars()).fold(0, |changes, (a, b)| {
     match a == b {
@@ -10,32 +7,23 @@ fn check_row(row1: &str, row2: &str) -> i32 {
   })
 }
 
-pub fn run() {
-    let mut f = File::open("src/day_02/a.input").expect("File not found");
-
-    let mut contents = String::new();
-    f.read_to_string(&mut contents)
-        .expect("Something went wrong reading the file");
-
-    let rows: Vec<&str> = contents
-      .split("\n")
-      .filter(|row| !row.is_empty())
-      .collect();
-
-    for (i, row1) in rows.iter().enumerate() {
-      for row2 in &rows[i..] {
-        if check_row(row1, row2) == 1 {
-          println!("Row1: {}, Row2: {}", row1, row2);
-
-          let common: String = row1.chars().zip(row2.chars())
+pub fn find_common_chars(ids: &[&str]) -> Option<String> {
+  for (i, row1) in ids.iter().enumerate() {
+    for row2 in &ids[i..] {
+      if check_row(row1, row2) == 1 {
+        return Some(
+          row1
+            .chars()
+            .zip(row2.chars())
             .filter(|(a, b)| a == b)
             .map(|(a, _)| a)
-            .collect();
-
-          println!("Common chars: {}", common);
-        }
+            .collect(),
+        );
       }
     }
+  }
+
+  None
 }
 
 #[cfg(test)]
@@ -1,2 +1,27 @@
 pub mod a;
-pub mod b;
\ No newline at end of file
+pub mod b;
+
+use aoc_macros::aoc;
+use error::ParseError;
+use solution::{Answer, Solution};
+
+pub struct Day02;
+
+#[aoc(day = 2)]
+impl Solution for Day02 {
+  type Input<'a> = Vec<&'a str>;
+
+  fn parse(input: &str) -> Result<Vec<&str>, ParseError> {
+    a::parse(input)
+  }
+
+  fn part_a(ids: &Vec<&str>) -> Answer {
+    a::checksum(ids).into()
+  }
+
+  fn part_b(ids: &Vec<&str>) -> Answer {
+    b::find_common_chars(ids)
+      .expect("No ids differ by exactly one character")
+      .into()
+  }
+}
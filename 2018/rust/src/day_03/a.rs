@@ -1,9 +1,13 @@
-use regex::Regex;
+use aoc_common::scan::ScanError;
+use aoc_macros::scan;
+use error::ParseError;
 use std::collections::BTreeMap;
 use std::collections::BTreeSet;
-use std::fs::File;
-use std::io::prelude::*;
+use std::fmt;
+use std::str::FromStr;
+use text;
 
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Claim {
   pub id: u32,
   pub left: u32,
@@ -12,31 +16,41 @@ pub struct Claim {
   pub height: u32,
 }
 
-pub fn parse_claims(contents: &str) -> Vec<Claim> {
-  let row_pattern = Regex::new(r"^#(\d+) @ (\d+),(\d+): (\d+)x(\d+)$").unwrap();
+/// A claim like `#1 @ 1,3: 4x4`.
+impl FromStr for Claim {
+  type Err = ScanError;
 
-  contents
-    .split("\n")
-    .filter(|row| !row.is_empty())
-    .map(|row| {
-      let caps = row_pattern.captures(row).unwrap();
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let (id, left, top, width, height) = scan!("#{id} @ {left},{top}: {width}x{height}", s)?;
 
-      Claim {
-        id: (&caps[1]).parse().expect("id is not number"),
-        left: (&caps[2]).parse().expect("left is not number"),
-        top: (&caps[3]).parse().expect("top is not number"),
-        width: (&caps[4]).parse().expect("width is not number"),
-        height: (&caps[5]).parse().expect("height is not number"),
-      }
-    }).collect()
+    Ok(Claim {
+      id,
+      left,
+      top,
+      width,
+      height,
+    })
+  }
+}
+
+impl fmt::Display for Claim {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "#{} @ {},{}: {}x{}", self.id, self.left, self.top, self.width, self.height)
+  }
+}
+
+pub fn parse_claims(contents: &str) -> Result<Vec<Claim>, ParseError> {
+  text::lines(contents)
+    .map(|(line, row)| row.parse().map_err(|error: ScanError| error.at(3, line, row)))
+    .collect()
 }
 
-pub fn find_overlaps(claims: &Vec<Claim>) -> BTreeSet<(u32, u32)> {
+pub fn find_overlaps(claims: &[Claim]) -> BTreeSet<(u32, u32)> {
   let (overlaps, _) = claims.iter().fold(
     (BTreeSet::new(), BTreeMap::new()),
     |(mut overlaps, mut rows), claim| {
       for x in (claim.left + 1)..=(claim.left + claim.width) {
-        let mut col = rows.entry(x).or_insert(BTreeSet::new());
+        let col = rows.entry(x).or_insert(BTreeSet::new());
         for y in (claim.top + 1)..=(claim.top + claim.height) {
           if !col.insert(y) {
             overlaps.insert((x, y));
@@ -51,14 +65,22 @@ pub fn find_overlaps(claims: &Vec<Claim>) -> BTreeSet<(u32, u32)> {
   overlaps
 }
 
-pub fn run() {
-  let mut f = File::open("src/day_03/a.input").expect("File not found");
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use proptest::prelude::*;
 
-  let mut contents = String::new();
-  f.read_to_string(&mut contents)
-    .expect("Something went wrong reading the file");
+  proptest! {
+    #[test]
+    fn test_claim_round_trip(id: u32, left: u32, top: u32, width: u32, height: u32) {
+      let claim = Claim { id, left, top, width, height };
 
-  let overlaps = find_overlaps(&parse_claims(&contents));
+      prop_assert_eq!(claim.to_string().parse(), Ok(claim));
+    }
+  }
 
-  println!("Value: {}", overlaps.iter().count());
+  #[test]
+  fn test_claim_display() {
+    assert_eq!("#1 @ 1,3: 4x4".parse::<Claim>().unwrap().to_string(), "#1 @ 1,3: 4x4");
+  }
 }
@@ -1,2 +1,28 @@
 pub mod a;
-pub mod b;
\ No newline at end of file
+pub mod b;
+
+use self::a::Claim;
+use aoc_macros::aoc;
+use error::ParseError;
+use solution::{Answer, Solution};
+
+pub struct Day03;
+
+#[aoc(day = 3)]
+impl Solution for Day03 {
+  type Input<'a> = Vec<Claim>;
+
+  fn parse(input: &str) -> Result<Vec<Claim>, ParseError> {
+    a::parse_claims(input)
+  }
+
+  fn part_a(claims: &Vec<Claim>) -> Answer {
+    a::find_overlaps(claims).len().into()
+  }
+
+  fn part_b(claims: &Vec<Claim>) -> Answer {
+    b::find_intact_claim(claims)
+      .expect("Every claim overlaps another")
+      .into()
+  }
+}
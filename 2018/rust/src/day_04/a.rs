@@ -1,9 +1,13 @@
+use aoc_common::scan::{Field, ScanError, Spanned};
+use aoc_macros::scan;
 use chrono::prelude::*;
+use error::ParseError;
 use itertools::{Itertools, PeekingNext};
 use std::collections::BTreeMap;
-use std::fs::File;
-use std::io::prelude::*;
+use std::fmt;
 use std::ops::RangeInclusive;
+use std::str::FromStr;
+use text;
 
 #[derive(Debug)]
 pub struct GuardSchema {
@@ -12,11 +16,87 @@ pub struct GuardSchema {
   pub asleep: Vec<RangeInclusive<NaiveDateTime>>,
 }
 
-fn parse_time(row: &str) -> NaiveDateTime {
-  NaiveDateTime::parse_from_str(&row[1..17], "%Y-%m-%d %H:%M").unwrap()
+pub type Guards = BTreeMap<usize, Vec<GuardSchema>>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Event {
+  BeginsShift(usize),
+  FallsAsleep,
+  WakesUp,
+}
+
+/// A line of the guard log, like `[1518-11-01 00:05] falls asleep`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogEntry {
+  pub time: NaiveDateTime,
+  pub event: Event,
+}
+
+impl FromStr for Event {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s {
+      "falls asleep" => Ok(Event::FallsAsleep),
+      "wakes up" => Ok(Event::WakesUp),
+      event => scan!("Guard #{id} begins shift", event)
+        .map(Event::BeginsShift)
+        .map_err(|_| "expected \"Guard #<id> begins shift\", \"falls asleep\" or \"wakes up\"".to_string()),
+    }
+  }
+}
+
+impl fmt::Display for Event {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      Event::BeginsShift(guard) => write!(f, "Guard #{} begins shift", guard),
+      Event::FallsAsleep => write!(f, "falls asleep"),
+      Event::WakesUp => write!(f, "wakes up"),
+    }
+  }
+}
+
+impl<'a> Field<'a> for Event {
+  fn parse(event: &'a str) -> Result<Event, String> {
+    event.parse()
+  }
+}
+
+impl FromStr for LogEntry {
+  type Err = ScanError;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    LogEntry::scan(s).map(|(entry, _)| entry)
+  }
+}
+
+impl fmt::Display for LogEntry {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "[{}] {}", self.time.format("%Y-%m-%d %H:%M"), self.event)
+  }
 }
 
-pub fn calculate_asleep_ratio(guards: &Vec<GuardSchema>, minute: u32) -> f64 {
+impl LogEntry {
+  /// Scans a line of the log, with where its event starts in the line.
+  fn scan(s: &str) -> Result<(LogEntry, usize), ScanError> {
+    let (year, month, day, hour, minute, event): (_, _, _, _, _, Spanned<Event>) =
+      scan!("[{year}-{month}-{day} {hour}:{minute}] {event}", s)?;
+    let time = NaiveDate::from_ymd_opt(year, month, day)
+      .and_then(|date| date.and_hms_opt(hour, minute, 0))
+      .ok_or_else(|| ScanError {
+        offset: 0,
+        message: "expected a valid date and time".to_string(),
+      })?;
+
+    Ok((LogEntry { time, event: event.value }, event.offset))
+  }
+
+  pub fn is_shift(&self) -> bool {
+    matches!(self.event, Event::BeginsShift(_))
+  }
+}
+
+pub fn calculate_asleep_ratio(guards: &[GuardSchema], minute: u32) -> f64 {
   guards
     .iter()
     .filter(|guard| {
@@ -33,55 +113,70 @@ fn as_range(
 ) -> Option<RangeInclusive<NaiveDateTime>> {
   match iterator.next() {
     None => None,
-    Some(start) => match iterator.next() {
-      None => None,
-      Some(end) => Some(start..=end),
-    },
+    Some(start) => iterator.next().map(|end| start..=end),
   }
 }
 
+/// Takes a shift and the naps during it from entries ordered by time.
 pub fn as_guard_schema<'a>(
-  iterator: &mut impl PeekingNext<Item = &'a &'a str>,
+  iterator: &mut impl PeekingNext<Item = &'a LogEntry>,
 ) -> Option<GuardSchema> {
-  iterator.next().map(|head| GuardSchema {
-    guard: head[26..(head
-                  .chars()
-                  .enumerate()
-                  .skip(26)
-                  .find_map(|(i, c)| if c.is_numeric() { None } else { Some(i) })
-                  .unwrap())]
-      .parse()
-      .expect("Guard id is not number"),
-    start_time: parse_time(head),
+  let head = iterator.next()?;
+  let guard = match head.event {
+    Event::BeginsShift(guard) => guard,
+    _ => return None,
+  };
+
+  Some(GuardSchema {
+    guard,
+    start_time: head.time,
     asleep: iterator
-      .peeking_take_while(|row| !row.contains("Guard"))
-      .map(|row| parse_time(row))
+      .peeking_take_while(|entry| !entry.is_shift())
+      .map(|entry| entry.time)
       .batching(as_range)
       .collect::<Vec<_>>(),
   })
 }
 
-pub fn run() {
-  let mut f = File::open("src/day_04/a.input").expect("File not found");
-
-  let mut contents = String::new();
-  f.read_to_string(&mut contents)
-    .expect("Something went wrong reading the file");
-
-  let mut lines: Vec<_> = contents.trim().lines().collect();
-
-  lines.sort();
+pub fn parse(contents: &str) -> Result<Guards, ParseError> {
+  let mut entries = text::lines(contents)
+    .map(|(line, row)| {
+      LogEntry::scan(row)
+        .map(|(entry, offset)| (line, row, offset, entry))
+        .map_err(|error| error.at(4, line, row))
+    })
+    .collect::<Result<Vec<_>, _>>()?;
+
+  entries.sort_by_key(|(_, _, _, entry)| entry.time);
+
+  match entries.first() {
+    None => return Err(ParseError::new(4, 1, "", 0, "expected a guard log entry")),
+    Some((line, row, offset, entry)) if !entry.is_shift() => {
+      return Err(ParseError::new(
+        4,
+        *line,
+        row,
+        *offset,
+        "the earliest entry must be a guard beginning a shift",
+      ));
+    }
+    Some(_) => (),
+  }
 
-  let guards_schemas: Vec<_> = lines.iter().batching(as_guard_schema).collect();
+  let entries: Vec<_> = entries.into_iter().map(|(_, _, _, entry)| entry).collect();
 
-  let guards: BTreeMap<usize, Vec<GuardSchema>> =
-    guards_schemas
-      .into_iter()
+  Ok(
+    entries
+      .iter()
+      .batching(as_guard_schema)
       .fold(BTreeMap::new(), |mut guards, schema| {
         guards.entry(schema.guard).or_insert(vec![]).push(schema);
         guards
-      });
+      }),
+  )
+}
 
+pub fn most_asleep_guard(guards: &Guards) -> usize {
   let (most_asleep, _) = guards
     .iter()
     .fold(None, |most_asleep: Option<(usize, u32)>, (id, schemas)| {
@@ -112,10 +207,46 @@ pub fn run() {
 
   minute_asleep_ratio.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
 
-  println!(
-    "Value: {} {:?}: {}",
-    most_asleep,
-    minute_asleep_ratio[0],
-    most_asleep * minute_asleep_ratio[0].0 as usize
-  );
+  most_asleep * minute_asleep_ratio[0].0 as usize
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use proptest::prelude::*;
+
+  fn event() -> impl Strategy<Value = Event> {
+    prop_oneof![
+      any::<usize>().prop_map(Event::BeginsShift),
+      Just(Event::FallsAsleep),
+      Just(Event::WakesUp),
+    ]
+  }
+
+  proptest! {
+    #[test]
+    fn test_log_entry_round_trip(
+      date in (1000..=9999i32, 1..=12u32, 1..=28u32),
+      hour in 0..24u32,
+      minute in 0..60u32,
+      event in event(),
+    ) {
+      let (year, month, day) = date;
+      let time = NaiveDate::from_ymd_opt(year, month, day).unwrap().and_hms_opt(hour, minute, 0).unwrap();
+      let entry = LogEntry { time, event };
+
+      prop_assert_eq!(entry.to_string().parse(), Ok(entry));
+    }
+  }
+
+  #[test]
+  fn test_log_entry_display() {
+    for row in &[
+      "[1518-11-01 00:00] Guard #10 begins shift",
+      "[1518-11-01 00:05] falls asleep",
+      "[1518-11-01 00:25] wakes up",
+    ] {
+      assert_eq!(row.parse::<LogEntry>().unwrap().to_string(), *row);
+    }
+  }
 }
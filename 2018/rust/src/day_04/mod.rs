@@ -1,2 +1,26 @@
 pub mod a;
-pub mod b;
\ No newline at end of file
+pub mod b;
+
+use self::a::Guards;
+use aoc_macros::aoc;
+use error::ParseError;
+use solution::{Answer, Solution};
+
+pub struct Day04;
+
+#[aoc(day = 4)]
+impl Solution for Day04 {
+  type Input<'a> = Guards;
+
+  fn parse(input: &str) -> Result<Guards, ParseError> {
+    a::parse(input)
+  }
+
+  fn part_a(guards: &Guards) -> Answer {
+    a::most_asleep_guard(guards).into()
+  }
+
+  fn part_b(guards: &Guards) -> Answer {
+    b::most_frequently_asleep_minute(guards).into()
+  }
+}
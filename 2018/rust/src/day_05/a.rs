@@ -1,5 +1,20 @@
-use std::fs::File;
-use std::io::prelude::*;
+use error::ParseError;
+use text;
+
+/// The units of the polymer, which may only be letters.
+pub fn parse(contents: &str) -> Result<Vec<char>, ParseError> {
+  let mut chars = vec![];
+
+  for (line, row) in text::lines(contents) {
+    if let Some(offset) = row.find(|c: char| !c.is_ascii_alphabetic()) {
+      return Err(ParseError::new(5, line, row, offset, "expected a polymer unit"));
+    }
+
+    chars.extend(row.chars());
+  }
+
+  Ok(chars)
+}
 
 pub fn compact_polymers(mut chars: Vec<char>) -> Vec<char> {
   loop {
@@ -33,17 +48,3 @@ pub fn compact_polymers(mut chars: Vec<char>) -> Vec<char> {
 
   chars
 }
-
-pub fn run() {
-  let mut f = File::open("src/day_05/a.input").expect("File not found");
-
-  let mut contents = String::new();
-  f.read_to_string(&mut contents)
-    .expect("Something went wrong reading the file");
-
-  let chars = contents.chars().collect();
-  let chars = compact_polymers(chars);
-
-  println!("Value: {}", chars.iter().collect::<String>());
-  println!("Value: {}", chars.len());
-}
@@ -1,19 +1,9 @@
 use day_05::a::compact_polymers;
-use std::fs::File;
-use std::io::prelude::*;
 
-pub fn run() {
-  let mut f = File::open("src/day_05/a.input").expect("File not found");
-
-  let mut contents = String::new();
-  f.read_to_string(&mut contents)
-    .expect("Something went wrong reading the file");
-
-  let chars: Vec<_> = contents.chars().collect();
-
-  let val = (b'a'..=b'z')
+pub fn shortest_polymer(chars: &[char]) -> usize {
+  (b'a'..=b'z')
     .map(|c_to_remove| {
-      println!("{}", (c_to_remove as char).to_string());
+      debug!("removing {}", c_to_remove as char);
       compact_polymers(
         chars
           .iter()
@@ -22,7 +12,5 @@ pub fn run() {
           .collect(),
       ).len()
     }).min()
-    .unwrap();
-
-  println!("Value: {}", val);
+    .unwrap()
 }
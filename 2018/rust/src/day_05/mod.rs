@@ -1,2 +1,25 @@
 pub mod a;
-pub mod b;
\ No newline at end of file
+pub mod b;
+
+use aoc_macros::aoc;
+use error::ParseError;
+use solution::{Answer, Solution};
+
+pub struct Day05;
+
+#[aoc(day = 5)]
+impl Solution for Day05 {
+  type Input<'a> = Vec<char>;
+
+  fn parse(input: &str) -> Result<Vec<char>, ParseError> {
+    a::parse(input)
+  }
+
+  fn part_a(chars: &Vec<char>) -> Answer {
+    a::compact_polymers(chars.clone()).len().into()
+  }
+
+  fn part_b(chars: &Vec<char>) -> Answer {
+    b::shortest_polymer(chars).into()
+  }
+}
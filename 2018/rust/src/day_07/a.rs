@@ -1,30 +1,61 @@
+use aoc_common::scan::ScanError;
+use aoc_macros::scan;
+use error::ParseError;
 use std::collections::{BTreeMap, BTreeSet};
-use std::fmt::Write;
-use std::fs::File;
-use std::io::prelude::*;
-
-pub type Steps<'a> = BTreeMap<&'a str, BTreeSet<&'a str>>;
-
-pub fn parse(input: &str) -> Steps {
-  input.lines().map(|line| (&line[5..6], &line[36..37])).fold(
-    BTreeMap::new(),
-    |mut steps, (pre_req, step)| {
-      steps.entry(pre_req).or_insert(BTreeSet::new());
-      steps.entry(step).or_insert(BTreeSet::new()).insert(pre_req);
-      steps
-    },
-  )
+use std::fmt::{self, Write};
+use std::str::FromStr;
+use text;
+
+/// Each step and the steps it depends on.
+pub type Steps = BTreeMap<char, BTreeSet<char>>;
+
+/// A dependency between steps, like
+/// `Step C must be finished before step A can begin.`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Edge {
+  pub pre_req: char,
+  pub step: char,
 }
 
-fn execute(mut steps: Steps) -> String {
+impl FromStr for Edge {
+  type Err = ScanError;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let (pre_req, step) = scan!("Step {pre_req} must be finished before step {step} can begin.", s)?;
+
+    Ok(Edge { pre_req, step })
+  }
+}
+
+impl fmt::Display for Edge {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(
+      f,
+      "Step {} must be finished before step {} can begin.",
+      self.pre_req, self.step
+    )
+  }
+}
+
+pub fn parse(input: &str) -> Result<Steps, ParseError> {
+  text::lines(input).try_fold(Steps::new(), |mut steps, (line, row)| {
+    let Edge { pre_req, step } = row.parse().map_err(|error: ScanError| error.at(7, line, row))?;
+
+    steps.entry(pre_req).or_insert(BTreeSet::new());
+    steps.entry(step).or_insert(BTreeSet::new()).insert(pre_req);
+    Ok(steps)
+  })
+}
+
+pub fn execute(mut steps: Steps) -> String {
   let mut visited = String::new();
 
-  while steps.len() > 0 {
+  while !steps.is_empty() {
     let mut possible_steps: Vec<_> = {
       steps
         .iter()
-        .filter(|(_, depends_on)| depends_on.len() == 0)
-        .map(|(step, _)| step.to_owned())
+        .filter(|(_, depends_on)| depends_on.is_empty())
+        .map(|(&step, _)| step)
         .collect()
     };
 
@@ -44,22 +75,10 @@ fn execute(mut steps: Steps) -> String {
   visited
 }
 
-pub fn run() {
-  let mut f = File::open("src/day_07/a.input").expect("File not found");
-
-  let mut contents = String::new();
-  f.read_to_string(&mut contents)
-    .expect("Something went wrong reading the file");
-
-  let steps = parse(&contents);
-  let val = execute(steps);
-
-  println!("Value: {}", val);
-}
-
 #[cfg(test)]
 mod tests {
   use super::*;
+  use proptest::prelude::*;
 
   #[test]
   fn test_parse() {
@@ -72,10 +91,10 @@ Step B must be finished before step E can begin.
 Step D must be finished before step E can begin.
 Step F must be finished before step E can begin.
 ",
-    );
+    ).unwrap();
 
     assert_eq!(steps.len(), 6);
-    assert_eq!(steps["E"].len(), 3);
+    assert_eq!(steps[&'E'].len(), 3);
   }
 
   #[test]
@@ -89,8 +108,28 @@ Step B must be finished before step E can begin.
 Step D must be finished before step E can begin.
 Step F must be finished before step E can begin.
 ",
-    ));
+    ).unwrap());
 
     assert_eq!(result, "CABDFE");
   }
+
+  proptest! {
+    #[test]
+    fn test_edge_round_trip(pre_req in prop::char::range('A', 'Z'), step in prop::char::range('A', 'Z')) {
+      let edge = Edge { pre_req, step };
+
+      prop_assert_eq!(edge.to_string().parse(), Ok(edge));
+    }
+  }
+
+  #[test]
+  fn test_parse_error() {
+    let error = parse("Step C must be finished before step A can begin.\nStep C must be\n").unwrap_err();
+
+    assert_eq!((error.line, error.column), (2, 15));
+    assert_eq!(
+      error.message,
+      "expected \" must be finished before step \" in \"Step <pre_req> must be finished before step <step> can begin.\""
+    );
+  }
 }
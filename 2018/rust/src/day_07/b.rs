@@ -1,34 +1,30 @@
 use crate::day_07::a::*;
-use std::fs::File;
-use std::io::prelude::*;
 
-fn execute(mut steps: Steps, workers: usize, extra_time: u32) -> u32 {
-  let mut executing_steps: Vec<(u32, String)> = vec![];
+pub fn execute(mut steps: Steps, workers: usize, extra_time: u32) -> u32 {
+  let mut executing_steps: Vec<(u32, char)> = vec![];
 
   for time in 0.. {
-    executing_steps = executing_steps
-      .into_iter()
-      .filter(|(done_time, step)| {
-        let is_done = done_time < &time;
-        if is_done {
-          steps.values_mut().for_each(|depends_on| {
-            depends_on.remove(step as &str);
-          });
-        }
+    executing_steps.retain(|(done_time, step)| {
+      let is_done = done_time < &time;
+      if is_done {
+        steps.values_mut().for_each(|depends_on| {
+          depends_on.remove(step);
+        });
+      }
 
-        !is_done
-      }).collect();
+      !is_done
+    });
 
     if executing_steps.len() < workers {
-      if steps.len() == 0 && executing_steps.len() == 0 {
+      if steps.is_empty() && executing_steps.is_empty() {
         return time;
       }
 
       let mut possible_steps: Vec<_> = {
         steps
           .iter()
-          .filter(|(_, depends_on)| depends_on.len() == 0)
-          .map(|(step, _)| step.to_owned())
+          .filter(|(_, depends_on)| depends_on.is_empty())
+          .map(|(&step, _)| step)
           .collect()
       };
 
@@ -37,32 +33,16 @@ fn execute(mut steps: Steps, workers: usize, extra_time: u32) -> u32 {
       let steps_to_take = possible_steps.iter().take(workers - executing_steps.len());
 
       for step_to_take in steps_to_take {
-        executing_steps.push((
-          time + extra_time + (step_to_take.bytes().next().unwrap() - b'A') as u32,
-          step_to_take.to_string(),
-        ));
+        executing_steps.push((time + extra_time + (*step_to_take as u32 - 'A' as u32), *step_to_take));
         steps.remove(step_to_take);
       }
     }
-    println!("{:?} {}", executing_steps, time);
+    trace!(time, "executing {:?}", executing_steps);
   }
 
   0
 }
 
-pub fn run() {
-  let mut f = File::open("src/day_07/a.input").expect("File not found");
-
-  let mut contents = String::new();
-  f.read_to_string(&mut contents)
-    .expect("Something went wrong reading the file");
-
-  let steps = parse(&contents);
-  let val = execute(steps, 5, 60);
-
-  println!("Value: {}", val);
-}
-
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -79,7 +59,7 @@ Step B must be finished before step E can begin.
 Step D must be finished before step E can begin.
 Step F must be finished before step E can begin.
 ",
-      ),
+      ).unwrap(),
       2,
       0,
     );
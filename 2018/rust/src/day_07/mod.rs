@@ -1,2 +1,26 @@
 pub mod a;
 pub mod b;
+
+use self::a::Steps;
+use aoc_macros::aoc;
+use error::ParseError;
+use solution::{Answer, Solution};
+
+pub struct Day07;
+
+#[aoc(day = 7)]
+impl Solution for Day07 {
+  type Input<'a> = Steps;
+
+  fn parse(input: &str) -> Result<Steps, ParseError> {
+    a::parse(input)
+  }
+
+  fn part_a(steps: &Steps) -> Answer {
+    a::execute(steps.clone()).into()
+  }
+
+  fn part_b(steps: &Steps) -> Answer {
+    b::execute(steps.clone(), 5, 60).into()
+  }
+}
@@ -1,13 +1,26 @@
-use std::fs::File;
-use std::io::prelude::*;
+use error::ParseError;
+use text;
 
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Node {
-  sub_nodes: Vec<Node>,
-  metadata: Vec<u32>,
+  pub sub_nodes: Vec<Node>,
+  pub metadata: Vec<u32>,
 }
 
 impl Node {
-  fn sum_metadata(&self) -> u32 {
+  pub fn new(sub_nodes: Vec<Node>, metadata: Vec<u32>) -> Node {
+    Node {
+      sub_nodes,
+      metadata,
+    }
+  }
+
+  /// A node without child nodes.
+  pub fn leaf(metadata: Vec<u32>) -> Node {
+    Node::new(vec![], metadata)
+  }
+
+  pub fn sum_metadata(&self) -> u32 {
     let own_sum: u32 = self.metadata.iter().sum();
     let child_sum: u32 = self.sub_nodes.iter().map(Node::sum_metadata).sum();
 
@@ -15,7 +28,7 @@ impl Node {
   }
 
   pub fn get_value(&self) -> u32 {
-    if self.sub_nodes.len() == 0 {
+    if self.sub_nodes.is_empty() {
       let own_sum: u32 = self.metadata.iter().sum();
       own_sum
     } else {
@@ -33,31 +46,48 @@ impl Node {
   }
 }
 
-pub fn parse_node<I>(contents: &mut I) -> Node
+/// Parses a node and its children, or `None` if `contents` ends before the node does.
+pub fn parse_node<I>(contents: &mut I) -> Option<Node>
 where
   I: Iterator<Item = u32>,
 {
-  let child_node_count = contents.next().expect("child node header not found");
-  let metadata_count = contents.next().expect("metadata header not found");
-
-  Node {
-    sub_nodes: (0..child_node_count)
-      .map(|_| parse_node(contents))
-      .collect(),
-    metadata: contents.take(metadata_count as usize).collect(),
+  let child_node_count = contents.next()?;
+  let metadata_count = contents.next()? as usize;
+
+  let sub_nodes = (0..child_node_count)
+    .map(|_| parse_node(contents))
+    .collect::<Option<_>>()?;
+  let metadata: Vec<_> = contents.take(metadata_count).collect();
+
+  if metadata.len() < metadata_count {
+    return None;
   }
+
+  Some(Node::new(sub_nodes, metadata))
 }
 
-pub fn run() {
-  let mut f = File::open("src/day_08/a.input").expect("File not found");
+pub fn parse(contents: &str) -> Result<Node, ParseError> {
+  let tokens: Vec<_> = text::tokens(contents).collect();
+
+  let values = tokens
+    .iter()
+    .map(|token| {
+      token
+        .text
+        .parse()
+        .map_err(|_| ParseError::new(8, token.line, token.row, token.offset, "expected a number"))
+    }).collect::<Result<Vec<u32>, _>>()?;
 
-  let mut contents = String::new();
-  f.read_to_string(&mut contents)
-    .expect("Something went wrong reading the file");
+  let mut remaining = values.iter().cloned();
+  let node = parse_node(&mut remaining).ok_or_else(|| {
+    let (line, row) = tokens.last().map_or((1, ""), |token| (token.line, token.row));
 
-  let mut values = contents.split(" ").map(|val| val.parse().unwrap());
-  let node = parse_node(&mut values);
-  let val = node.sum_metadata();
+    ParseError::new(8, line, row, row.len(), "unexpected end of the tree")
+  })?;
+
+  if let Some(token) = tokens.get(values.len() - remaining.len()) {
+    return Err(ParseError::new(8, token.line, token.row, token.offset, "unexpected number after the tree"));
+  }
 
-  println!("Value: {}", val);
+  Ok(node)
 }
@@ -1,2 +1,26 @@
 pub mod a;
-pub mod b;
\ No newline at end of file
+pub mod b;
+
+use self::a::Node;
+use aoc_macros::aoc;
+use error::ParseError;
+use solution::{Answer, Solution};
+
+pub struct Day08;
+
+#[aoc(day = 8)]
+impl Solution for Day08 {
+  type Input<'a> = Node;
+
+  fn parse(input: &str) -> Result<Node, ParseError> {
+    a::parse(input)
+  }
+
+  fn part_a(node: &Node) -> Answer {
+    node.sum_metadata().into()
+  }
+
+  fn part_b(node: &Node) -> Answer {
+    b::root_value(node).into()
+  }
+}
@@ -1,20 +1,60 @@
+use aoc_common::scan::{ScanError, Spanned};
+use aoc_macros::scan;
+use error::ParseError;
 use itertools::Itertools;
-use regex::Regex;
 use std::collections::BTreeMap;
 use std::fmt;
-use std::fs::File;
-use std::io::prelude::*;
 use std::rc::Rc;
+use std::str::FromStr;
+use text;
 
-pub fn parse(input: &str) -> (u32, u32) {
-  let pattern = Regex::new(r"^(\d+) players; last marble is worth (\d+) points$").unwrap();
+/// The game to play, like `10 players; last marble is worth 1618 points`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Game {
+  pub num_players: u32,
+  pub last_marble: u32,
+}
 
-  let captures = pattern.captures(input).expect("Not matching pattern");
+impl FromStr for Game {
+  type Err = ScanError;
 
-  (
-    captures.get(1).unwrap().as_str().parse().unwrap(),
-    captures.get(2).unwrap().as_str().parse().unwrap(),
-  )
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let (num_players, last_marble): (Spanned<u32>, _) =
+      scan!("{players} players; last marble is worth {points} points", s)?;
+
+    if num_players.value == 0 {
+      return Err(ScanError {
+        offset: num_players.offset,
+        message: "expected at least one player".to_string(),
+      });
+    }
+
+    Ok(Game {
+      num_players: num_players.value,
+      last_marble,
+    })
+  }
+}
+
+impl fmt::Display for Game {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(
+      f,
+      "{} players; last marble is worth {} points",
+      self.num_players, self.last_marble
+    )
+  }
+}
+
+pub fn parse(input: &str) -> Result<Game, ParseError> {
+  let mut lines = text::lines(input);
+  let (line, row) = lines.next().unwrap_or((1, ""));
+
+  if let Some((line, row)) = lines.next() {
+    return Err(ParseError::new(9, line, row, 0, "expected a single line"));
+  }
+
+  row.parse().map_err(|error: ScanError| error.at(9, line, row))
 }
 
 struct Round {
@@ -64,7 +104,7 @@ fn play_rounds() -> impl Iterator<Item = Round> {
 
       Round {
         marbles: (*marbles).clone(),
-        current_index: current_index,
+        current_index,
         score: removed_marble + marble,
         current_marble: marble,
         removed_marble: Some(removed_marble),
@@ -88,7 +128,7 @@ fn play_rounds() -> impl Iterator<Item = Round> {
 
       Round {
         marbles: (*marbles).clone(),
-        current_index: current_index,
+        current_index,
         score: 0,
         current_marble: marble,
         removed_marble: None,
@@ -128,7 +168,7 @@ fn play(num_players: u32) -> impl Iterator<Item = GameRound> {
 }
 
 #[allow(unused)]
-fn print<'a>(game: impl Iterator<Item = &'a GameRound>) -> () {
+fn print<'a>(game: impl Iterator<Item = &'a GameRound>) {
   let output = game
     .map(|game_round| format!("[{}] {:?}", game_round.player, game_round.round))
     .join("\n");
@@ -136,52 +176,53 @@ fn print<'a>(game: impl Iterator<Item = &'a GameRound>) -> () {
   println!("Value: {}", output);
 }
 
-pub fn run() {
-  let mut f = File::open("src/day_09/a.input").expect("File not found");
-
-  let mut contents = String::new();
-  f.read_to_string(&mut contents)
-    .expect("Something went wrong reading the file");
-
-  let (num_players, highest_score) = parse(&contents);
-  // let (num_players, highest_score) = parse("9 players; last marble is worth 32 points");
-  // let (num_players, highest_score) = parse("10 players; last marble is worth 1618 points");
-
-  let game = play(num_players).take_while(|round| round.round.current_marble <= highest_score);
+pub fn winning_score(num_players: u32, last_marble: u32) -> u32 {
+  let game = play(num_players).take_while(|round| round.round.current_marble <= last_marble);
 
   // let game = game.collect::<Vec<_>>();
   // print(game.iter());
 
   let last_round = game.last().unwrap();
 
-  println!(
-    "{} players; last marble is worth {} points\n",
-    num_players, highest_score
-  );
-
-  println!(
-    "Winning player: {}, with score: {}",
-    last_round
-      .player_scores
-      .iter()
-      .max_by_key(|(_, score)| *score)
-      .unwrap()
-      .0,
-    last_round.player_scores.values().max().unwrap()
-  );
+  *last_round.player_scores.values().max().unwrap()
 }
 
 #[cfg(test)]
 mod tests {
   use super::*;
+  use proptest::prelude::*;
+
+  fn game(num_players: u32, last_marble: u32) -> Game {
+    Game {
+      num_players,
+      last_marble,
+    }
+  }
 
   #[test]
   fn test_parse() {
     let input1 = parse("9 players; last marble is worth 32 points");
     let input2 = parse("10 players; last marble is worth 1618 points");
 
-    assert_eq!(input1, (9, 32));
-    assert_eq!(input2, (10, 1618));
+    assert_eq!(input1, Ok(game(9, 32)));
+    assert_eq!(input2, Ok(game(10, 1618)));
+    assert_eq!(parse("9 players; last marble is worth 32 points\n").unwrap(), game(9, 32));
+    assert_eq!(parse("9 players; last marble is worth points").unwrap_err().column, 39);
+    assert_eq!(parse("9 players, last marble is worth 32 points").unwrap_err().column, 10);
+
+    let error = parse("0 players; last marble is worth 25 points").unwrap_err();
+
+    assert_eq!((error.column, error.message.as_str()), (1, "expected at least one player"));
+    assert_eq!(parse("9 players; last marble is worth 99999999999 points").unwrap_err().column, 33);
+  }
+
+  proptest! {
+    #[test]
+    fn test_game_round_trip(num_players in 1..=u32::MAX, last_marble: u32) {
+      let game = game(num_players, last_marble);
+
+      prop_assert_eq!(game.to_string().parse(), Ok(game));
+    }
   }
 
   #[test]
@@ -1,8 +1,5 @@
-use regex::Regex;
 use std::cell::RefCell;
 use std::collections::BTreeMap;
-use std::fs::File;
-use std::io::prelude::*;
 use std::rc::Rc;
 
 type MarblePointer = Rc<RefCell<MarbleNode>>;
@@ -73,17 +70,6 @@ impl Marble for MarblePointer {
   }
 }
 
-fn parse(input: &str) -> (u32, u32) {
-  let pattern = Regex::new(r"^(\d+) players; last marble is worth (\d+) points$").unwrap();
-
-  let captures = pattern.captures(input).expect("Not matching pattern");
-
-  (
-    captures.get(1).unwrap().as_str().parse().unwrap(),
-    captures.get(2).unwrap().as_str().parse().unwrap(),
-  )
-}
-
 #[derive(Debug)]
 struct Round {
   score: u64,
@@ -120,6 +106,7 @@ pub fn player_tracker(num_players: u32) -> impl Iterator<Item = u32> {
   (1..=num_players).cycle()
 }
 
+#[allow(unused)]
 #[derive(Debug)]
 struct GameRound {
   round: Round,
@@ -127,7 +114,12 @@ struct GameRound {
   score: u64,
 }
 
-fn play(num_players: u32, num_rounds: u64) -> u64 {
+pub fn play(num_players: u32, num_rounds: u64) -> u64 {
+  // The rounds start at marble 1, so nobody scores before it.
+  if num_rounds == 0 {
+    return 0;
+  }
+
   let mut player_scores = BTreeMap::new();
 
   play_rounds()
@@ -149,37 +141,37 @@ fn play(num_players: u32, num_rounds: u64) -> u64 {
   *player_scores.values().max().unwrap()
 }
 
-pub fn run() {
-  let mut f = File::open("src/day_09/a.input").expect("File not found");
-
-  let mut contents = String::new();
-  f.read_to_string(&mut contents)
-    .expect("Something went wrong reading the file");
-
-  let (num_players, num_rounds) = parse(&contents);
-
-  let num_rounds = num_rounds as u64 * 100;
-  println!("num_rounds: {}", num_rounds);
-  let highest_score = play(num_players, num_rounds);
-
-  println!(
-    "{} players; last marble is worth {} points\n",
-    num_players, num_rounds
-  );
-
-  println!("Winning score: {}", highest_score);
-}
-
 #[cfg(test)]
 mod tests {
   use super::*;
+  use day_09::a::{parse, Game};
 
   #[test]
   fn test_parse() {
     let input1 = parse("9 players; last marble is worth 32 points");
     let input2 = parse("10 players; last marble is worth 1618 points");
 
-    assert_eq!(input1, (9, 32));
-    assert_eq!(input2, (10, 1618));
+    assert_eq!(
+      input1,
+      Ok(Game {
+        num_players: 9,
+        last_marble: 32
+      })
+    );
+    assert_eq!(
+      input2,
+      Ok(Game {
+        num_players: 10,
+        last_marble: 1618
+      })
+    );
+  }
+
+  #[test]
+  fn test_play() {
+    assert_eq!(play(9, 0), 0);
+    assert_eq!(play(9, 25), 32);
+    assert_eq!(play(10, 1618), 8317);
+    assert_eq!(play(30, 5807), 37305);
   }
 }
@@ -1,2 +1,29 @@
 pub mod a;
-pub mod b;
\ No newline at end of file
+pub mod b;
+
+use self::a::Game;
+use aoc_macros::aoc;
+use error::ParseError;
+use solution::{Answer, Solution};
+
+pub struct Day09;
+
+#[aoc(day = 9)]
+impl Solution for Day09 {
+  type Input<'a> = Game;
+
+  fn parse(input: &str) -> Result<Game, ParseError> {
+    a::parse(input)
+  }
+
+  fn part_a(game: &Game) -> Answer {
+    a::winning_score(game.num_players, game.last_marble).into()
+  }
+
+  fn part_b(game: &Game) -> Answer {
+    let num_rounds = u64::from(game.last_marble) * 100;
+    debug!(num_rounds, "playing");
+
+    b::play(game.num_players, num_rounds).into()
+  }
+}
@@ -0,0 +1,26 @@
+extern crate aoc_common;
+extern crate aoc_macros;
+extern crate chrono;
+extern crate itertools;
+#[cfg(test)]
+extern crate proptest;
+#[macro_use]
+extern crate tracing;
+
+// The days refer to these as `solution::`, `error::` and `text::`.
+pub use aoc_common::{error, solution, text};
+
+/// The year the days in this crate are registered under.
+pub const YEAR: u32 = 2018;
+
+/// Where the days and their inputs live.
+pub const SOURCE_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src");
+
+pub mod day_01;
+pub mod day_02;
+pub mod day_03;
+pub mod day_04;
+pub mod day_05;
+pub mod day_07;
+pub mod day_08;
+pub mod day_09;
@@ -1,16 +0,0 @@
-extern crate chrono;
-extern crate itertools;
-extern crate regex;
-
-pub mod day_01;
-pub mod day_02;
-pub mod day_03;
-pub mod day_04;
-pub mod day_05;
-pub mod day_07;
-pub mod day_08;
-pub mod day_09;
-
-fn main() {
-    day_09::b::run();
-}
@@ -0,0 +1,135 @@
+extern crate aoc_2018;
+extern crate aoc_common;
+
+use aoc_2018::day_03::a::{find_overlaps, parse_claims};
+use aoc_2018::day_03::b::find_intact_claim;
+use aoc_2018::day_04;
+use aoc_2018::day_05::a::compact_polymers;
+use aoc_2018::day_07::a::{execute, parse};
+use aoc_2018::day_08::a::{self, parse_node, Node};
+use aoc_2018::YEAR;
+use aoc_common::registry;
+use aoc_common::solution::Part;
+
+#[test]
+fn test_day_03() {
+  let claims = parse_claims("#1 @ 1,3: 4x4\n#2 @ 3,1: 4x4\n#3 @ 5,5: 2x2\n").unwrap();
+
+  assert_eq!(claims[2].width, 2);
+  assert_eq!(find_overlaps(&claims).len(), 4);
+  assert_eq!(find_intact_claim(&claims), Some(3));
+}
+
+#[test]
+fn test_day_05() {
+  let polymer: String = compact_polymers("dabAcCaCBAcCcaDA".chars().collect())
+    .into_iter()
+    .collect();
+
+  assert_eq!(polymer, "dabCBAcaDA");
+}
+
+#[test]
+fn test_day_07() {
+  let steps = parse(
+    "Step C must be finished before step A can begin.
+Step C must be finished before step F can begin.
+Step A must be finished before step B can begin.
+Step A must be finished before step D can begin.
+Step B must be finished before step E can begin.
+Step D must be finished before step E can begin.
+Step F must be finished before step E can begin.
+",
+  ).unwrap();
+
+  assert_eq!(steps[&'E'].len(), 3);
+  assert_eq!(execute(steps), "CABDFE");
+}
+
+#[test]
+fn test_day_08() {
+  let mut values = [2, 3, 0, 3, 10, 11, 12, 1, 1, 0, 1, 99, 2, 1, 1, 2].iter().cloned();
+  let node = parse_node(&mut values).unwrap();
+  let expected = Node::new(
+    vec![
+      Node::leaf(vec![10, 11, 12]),
+      Node::new(vec![Node::leaf(vec![99])], vec![2]),
+    ],
+    vec![1, 1, 2],
+  );
+
+  assert_eq!(node, expected);
+  assert_eq!(node.sub_nodes[1].metadata, vec![2]);
+  assert_eq!(node.sum_metadata(), 138);
+  assert_eq!(node.get_value(), 66);
+}
+
+#[test]
+fn test_parse_error() {
+  let error = parse_claims("#1 @ 1,3: 4x4\n#2 @ 3,1 4x4\n").unwrap_err();
+
+  assert_eq!((error.day, error.line, error.column), (3, 2, 9));
+  assert_eq!(error.snippet, "#2 @ 3,1 4x4");
+
+  let error = a::parse("0 1 5\n2\n").unwrap_err();
+
+  assert_eq!((error.day, error.line, error.column), (8, 2, 1));
+  assert_eq!(a::parse("0 1 x").unwrap_err().column, 5);
+  assert_eq!(a::parse("1 1 0").unwrap_err().column, 6);
+
+  let error = day_04::a::parse("[1518-11-01 00:00] Guard #x begins shift").unwrap_err();
+
+  assert_eq!((error.day, error.column), (4, 20));
+  assert_eq!(error.message, "expected \"Guard #<id> begins shift\", \"falls asleep\" or \"wakes up\"");
+  assert_eq!(day_04::a::parse("[1518-13-01 00:00] wakes up").unwrap_err().column, 1);
+  assert_eq!(
+    day_04::a::parse("1518-11-01 00:00 wakes up").unwrap_err().message,
+    "expected \"[\" in \"[<year>-<month>-<day> <hour>:<minute>] <event>\""
+  );
+
+  let error = day_04::a::parse("[1518-11-01 00:05] falls asleep\n[1518-11-01 00:00] wakes up").unwrap_err();
+
+  assert_eq!((error.line, error.column), (2, 20));
+  assert_eq!(error.message, "the earliest entry must be a guard beginning a shift");
+  assert_eq!(day_04::a::parse("\n").unwrap_err().message, "expected a guard log entry");
+}
+
+#[test]
+fn test_inputs_are_normalized() {
+  let answers = |day: u32, input: &str| {
+    let (_, solve, parts) = registry::day(YEAR, day).remove(0);
+    let solved = solve(input, &parts, &mut |_| ()).unwrap();
+
+    solved.answers.iter().map(|(_, answer, _)| answer.to_string()).collect::<Vec<_>>()
+  };
+
+  assert_eq!(answers(1, "\u{feff}+3\r\n+3\r\n+4\r\n-2\r\n-4\r\n"), ["4", "10"]);
+  assert_eq!(answers(5, "dabAcCaCBAcCcaDA\n")[0], "10");
+  assert_eq!(answers(1, "+3 \n+3\t\n+4\n-2\n-4")[0], "4");
+  assert_eq!(
+    answers(7, "Step C must be finished before step A can begin. \nStep A must be finished before step B can begin.")[0],
+    "CAB"
+  );
+  assert_eq!(answers(8, "2 3 0 3 10 11 12 1 1 0 1 99 2 1 1 2\r\n"), ["138", "66"]);
+}
+
+#[test]
+fn test_registry() {
+  let day_09: Vec<_> = registry::day(YEAR, 9).into_iter().map(|(_, _, parts)| parts).collect();
+
+  assert_eq!(day_09, vec![vec![Part::A, Part::B]]);
+  assert!(registry::day(YEAR, 6).is_empty());
+  assert!(registry::days(2017).is_empty());
+}
+
+#[test]
+fn test_days_are_registered_in_order() {
+  let days: Vec<_> = registry::days(YEAR)
+    .into_iter()
+    .map(|(day, _, parts)| (day, parts))
+    .collect();
+
+  assert_eq!(days[0], (1, vec![Part::A, Part::B]));
+  assert!(days.contains(&(9, vec![Part::A, Part::B])));
+  assert!(days.windows(2).all(|pair| pair[0].0 < pair[1].0));
+}
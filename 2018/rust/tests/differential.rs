@@ -0,0 +1,76 @@
+extern crate aoc_2018;
+extern crate aoc_common;
+extern crate proptest;
+
+use aoc_2018::day_09::{self, a::Game};
+use aoc_common::solution::Answer;
+use proptest::prelude::*;
+use proptest::test_runner::{Config, TestCaseError, TestRunner};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+/// How long both implementations may take on an input before it counts as a hang.
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Asserts that two implementations of the same part agree on every input
+/// `inputs` generates, shrinking the first input they disagree on, panic on
+/// or do not finish within `TIMEOUT`.
+fn assert_agree<S, A, B>(inputs: S, a: A, b: B)
+where
+  S: Strategy,
+  S::Value: Send + 'static,
+  A: Fn(&S::Value) -> Answer + Send + Sync + 'static,
+  B: Fn(&S::Value) -> Answer + Send + Sync + 'static,
+{
+  let (a, b) = (Arc::new(a), Arc::new(b));
+  let mut runner = TestRunner::new(Config {
+    source_file: Some(file!()),
+    ..Config::default()
+  });
+  let result = runner.run(&inputs, |input| {
+    let (a, b) = (a.clone(), b.clone());
+    let (sender, receiver) = mpsc::channel();
+
+    // A hung implementation is left running, as nothing can stop its thread.
+    thread::spawn(move || {
+      let _ = sender.send((a(&input), b(&input)));
+    });
+
+    match receiver.recv_timeout(TIMEOUT) {
+      Ok((a, b)) => {
+        prop_assert_eq!(a, b);
+        Ok(())
+      }
+      Err(RecvTimeoutError::Timeout) => Err(TestCaseError::fail(format!("no answer after {:?}", TIMEOUT))),
+      Err(RecvTimeoutError::Disconnected) => Err(TestCaseError::fail("panicked")),
+    }
+  });
+
+  if let Err(error) = result {
+    panic!("{}", error);
+  }
+}
+
+/// Games as `day_09::a::parse` accepts them, so a game it should reject but
+/// lets through shows up as a panic.
+fn marble_games() -> impl Strategy<Value = Game> {
+  (0..=50u32, 0..=1000u32).prop_filter_map("rejected by parse", |(num_players, last_marble)| {
+    let game = Game {
+      num_players,
+      last_marble,
+    };
+
+    day_09::a::parse(&game.to_string()).ok()
+  })
+}
+
+#[test]
+fn test_day_09() {
+  assert_agree(
+    marble_games(),
+    |game| day_09::a::winning_score(game.num_players, game.last_marble).into(),
+    |game| day_09::b::play(game.num_players, game.last_marble.into()).into(),
+  );
+}
@@ -0,0 +1,59 @@
+extern crate aoc_common;
+extern crate aoc_macros;
+
+use aoc_common::error::ParseError;
+use aoc_common::registry;
+use aoc_common::solution::{Answer, Part, Solution};
+use aoc_macros::aoc;
+
+// The registrations below go under this year, apart from the real ones.
+const YEAR: u32 = 1;
+
+struct Counted;
+
+#[aoc(day = 1, part = "a")]
+impl Solution for Counted {
+  type Input<'a> = usize;
+
+  fn parse(input: &str) -> Result<usize, ParseError> {
+    Ok(input.len())
+  }
+
+  fn part_a(len: &usize) -> Answer {
+    (*len).into()
+  }
+
+  fn part_b(_: &usize) -> Answer {
+    "part b of the part a impl".into()
+  }
+}
+
+struct Reversed;
+
+#[aoc(day = 1, part = "b")]
+impl Solution for Reversed {
+  type Input<'a> = String;
+
+  fn parse(input: &str) -> Result<String, ParseError> {
+    Ok(input.chars().rev().collect())
+  }
+
+  fn part_a(_: &String) -> Answer {
+    "part a of the part b impl".into()
+  }
+
+  fn part_b(reversed: &String) -> Answer {
+    reversed.as_str().into()
+  }
+}
+
+#[test]
+fn test_parts_registered_on_different_impls() {
+  let answers: Vec<_> = registry::day(YEAR, 1)
+    .into_iter()
+    .flat_map(|(_, solve, parts)| solve("abc", &parts, &mut |_| ()).unwrap().answers)
+    .map(|(part, answer, _)| (part, answer.to_string()))
+    .collect();
+
+  assert_eq!(answers, vec![(Part::A, "3".to_string()), (Part::B, "cba".to_string())]);
+}
@@ -0,0 +1,174 @@
+use solution::Part;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use toml::{Table, Value};
+
+#[derive(Debug)]
+pub enum AnswersError {
+  Io(PathBuf, io::Error),
+  Parse(PathBuf, String),
+}
+
+impl fmt::Display for AnswersError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      AnswersError::Io(path, error) => write!(f, "could not access {}: {}", path.display(), error),
+      AnswersError::Parse(path, error) => write!(f, "could not parse {}: {}", path.display(), error),
+    }
+  }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Check<'a> {
+  Correct,
+  Wrong { expected: &'a str },
+  Unrecorded,
+}
+
+/// Known correct answers, stored as TOML tables keyed by year and day:
+///
+/// ```toml
+/// [2018.7]
+/// a = "CABDFE"
+/// b = "15"
+/// ```
+#[derive(Debug)]
+pub struct Answers {
+  path: PathBuf,
+  answers: BTreeMap<(u32, u32, Part), String>,
+}
+
+impl Answers {
+  /// Loads the answers at `path`, which may not exist yet.
+  pub fn load(path: &Path) -> Result<Answers, AnswersError> {
+    let contents = match fs::read_to_string(path) {
+      Ok(contents) => contents,
+      Err(ref error) if error.kind() == io::ErrorKind::NotFound => String::new(),
+      Err(error) => return Err(AnswersError::Io(path.to_path_buf(), error)),
+    };
+
+    Ok(Answers {
+      path: path.to_path_buf(),
+      answers: parse(&contents).map_err(|error| AnswersError::Parse(path.to_path_buf(), error))?,
+    })
+  }
+
+  pub fn get(&self, year: u32, day: u32, part: Part) -> Option<&str> {
+    self.answers.get(&(year, day, part)).map(String::as_str)
+  }
+
+  pub fn insert(&mut self, year: u32, day: u32, part: Part, answer: String) {
+    self.answers.insert((year, day, part), answer);
+  }
+
+  pub fn check(&self, year: u32, day: u32, part: Part, answer: &str) -> Check<'_> {
+    match self.get(year, day, part) {
+      None => Check::Unrecorded,
+      Some(expected) if expected == answer => Check::Correct,
+      Some(expected) => Check::Wrong { expected },
+    }
+  }
+
+  pub fn save(&self) -> Result<(), AnswersError> {
+    fs::write(&self.path, format(&self.answers))
+      .map_err(|error| AnswersError::Io(self.path.clone(), error))
+  }
+}
+
+fn parse(contents: &str) -> Result<BTreeMap<(u32, u32, Part), String>, String> {
+  let table: Table = contents.parse().map_err(|error: toml::de::Error| error.to_string())?;
+  let mut answers = BTreeMap::new();
+
+  for (year, days) in &table {
+    let year_number = year
+      .parse()
+      .map_err(|_| format!("\"{}\" is not a year", year))?;
+    let days = days
+      .as_table()
+      .ok_or_else(|| format!("{} is not a table of days", year))?;
+
+    for (day, parts) in days {
+      let day_number = day
+        .parse()
+        .map_err(|_| format!("\"{}.{}\" is not a day", year, day))?;
+      let parts = parts
+        .as_table()
+        .ok_or_else(|| format!("{}.{} is not a table of parts", year, day))?;
+
+      for (part, answer) in parts {
+        let answer = answer
+          .as_str()
+          .ok_or_else(|| format!("{}.{}.{} is not a string", year, day, part))?;
+
+        answers.insert((year_number, day_number, part.parse()?), answer.to_string());
+      }
+    }
+  }
+
+  Ok(answers)
+}
+
+fn format(answers: &BTreeMap<(u32, u32, Part), String>) -> String {
+  let mut table = Table::new();
+
+  for ((year, day, part), answer) in answers {
+    let days = table
+      .entry(year.to_string())
+      .or_insert_with(|| Value::Table(Table::new()));
+    let parts = days
+      .as_table_mut()
+      .expect("Year is a table")
+      .entry(day.to_string())
+      .or_insert_with(|| Value::Table(Table::new()));
+
+    parts
+      .as_table_mut()
+      .expect("Day is a table")
+      .insert(part.to_string(), Value::String(answer.clone()));
+  }
+
+  table.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_round_trip() {
+    let mut answers = BTreeMap::new();
+    answers.insert((2018, 10, Part::A), "10".to_string());
+    answers.insert((2018, 9, Part::B), "3352507536".to_string());
+    answers.insert((2018, 9, Part::A), "399645".to_string());
+
+    let formatted = format(&answers);
+
+    assert!(formatted.find("[2018.9]").unwrap() < formatted.find("[2018.10]").unwrap());
+    assert_eq!(parse(&formatted), Ok(answers));
+  }
+
+  #[test]
+  fn test_check() {
+    let answers = Answers {
+      path: PathBuf::from("answers.toml"),
+      answers: parse("[2018.7]\na = \"CABDFE\"").unwrap(),
+    };
+
+    assert_eq!(answers.check(2018, 7, Part::A, "CABDFE"), Check::Correct);
+    assert_eq!(
+      answers.check(2018, 7, Part::A, "CABFDE"),
+      Check::Wrong { expected: "CABDFE" }
+    );
+    assert_eq!(answers.check(2018, 7, Part::B, "15"), Check::Unrecorded);
+  }
+
+  #[test]
+  fn test_parse_errors() {
+    assert!(parse("[2018.7]\nc = \"1\"").is_err());
+    assert!(parse("[2018.seven]\na = \"1\"").is_err());
+    assert!(parse("[2018.7]\na = 1").is_err());
+  }
+}
@@ -0,0 +1,203 @@
+use error::ParseError;
+use memory::Allocations;
+use report::{format_bytes, format_duration};
+use serde_json;
+use solution::{Part, Solve};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+#[derive(Debug)]
+pub enum BenchError {
+  Io(PathBuf, io::Error),
+  Json(PathBuf, serde_json::Error),
+}
+
+impl fmt::Display for BenchError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      BenchError::Io(path, error) => write!(f, "could not access {}: {}", path.display(), error),
+      BenchError::Json(path, error) => write!(f, "could not parse {}: {}", path.display(), error),
+    }
+  }
+}
+
+/// Summary of a set of timing samples, in nanoseconds.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+  pub min: u64,
+  pub median: u64,
+  pub mean: f64,
+  pub std_dev: f64,
+}
+
+impl Stats {
+  pub fn from_samples(samples: &[Duration]) -> Stats {
+    let mut nanos: Vec<u64> = samples.iter().map(|sample| sample.as_nanos() as u64).collect();
+    nanos.sort_unstable();
+
+    let count = nanos.len() as f64;
+    let mean = nanos.iter().sum::<u64>() as f64 / count;
+    let variance = nanos
+      .iter()
+      .map(|&sample| (sample as f64 - mean).powi(2))
+      .sum::<f64>()
+      / count;
+    let middle = nanos.len() / 2;
+    let median = if nanos.len().is_multiple_of(2) {
+      (nanos[middle - 1] + nanos[middle]) / 2
+    } else {
+      nanos[middle]
+    };
+
+    Stats {
+      min: nanos[0],
+      median,
+      mean,
+      std_dev: variance.sqrt(),
+    }
+  }
+}
+
+/// Timings of benchmarking one part of a day.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Bench {
+  pub year: u32,
+  pub day: u32,
+  pub part: String,
+  pub runs: usize,
+  pub warmup: usize,
+  pub parse: Stats,
+  pub solve: Stats,
+  /// What solving allocated in the last run, when allocations are counted.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub allocations: Option<Allocations>,
+}
+
+/// Runs `part` of `solve` `warmup` times untimed and `runs` times timed.
+pub fn bench(
+  year: u32,
+  day: u32,
+  solve: Solve,
+  input: &str,
+  part: Part,
+  warmup: usize,
+  runs: usize,
+) -> Result<Bench, ParseError> {
+  for _ in 0..warmup {
+    solve(input, &[part], &mut |_| ())?;
+  }
+
+  let mut parse = vec![];
+  let mut solve_times = vec![];
+  let mut allocations = None;
+
+  for _ in 0..runs {
+    let solved = solve(input, &[part], &mut |_| ())?;
+
+    parse.push(solved.parse_time);
+    solve_times.push(solved.answers[0].2);
+    allocations = solved.allocations[0];
+  }
+
+  Ok(Bench {
+    year,
+    day,
+    part: part.to_string(),
+    runs,
+    warmup,
+    parse: Stats::from_samples(&parse),
+    solve: Stats::from_samples(&solve_times),
+    allocations,
+  })
+}
+
+pub fn load(path: &Path) -> Result<Vec<Bench>, BenchError> {
+  let contents = fs::read_to_string(path).map_err(|error| BenchError::Io(path.to_path_buf(), error))?;
+
+  serde_json::from_str(&contents).map_err(|error| BenchError::Json(path.to_path_buf(), error))
+}
+
+pub fn save(path: &Path, benches: &[Bench]) -> Result<(), BenchError> {
+  let contents = serde_json::to_string_pretty(benches).expect("Benches serialize to JSON");
+
+  fs::write(path, contents + "\n").map_err(|error| BenchError::Io(path.to_path_buf(), error))
+}
+
+fn nanos(nanos: f64) -> String {
+  format_duration(Duration::from_nanos(nanos.round() as u64))
+}
+
+fn change(current: u64, baseline: u64) -> String {
+  format!(
+    "{:+.1}%",
+    (current as f64 - baseline as f64) / baseline as f64 * 100.0
+  )
+}
+
+/// Formats `bench`, comparing medians with `baseline` when there is one.
+pub fn format(bench: &Bench, baseline: Option<&Bench>) -> String {
+  let mut output = format!(
+    "Day {} {} ({} runs, {} warm-up)\n       {:>10}  {:>10}  {:>10}  {:>10}\n",
+    bench.day, bench.part, bench.runs, bench.warmup, "min", "median", "mean", "std dev"
+  );
+
+  for (name, stats, baseline) in [
+    ("parse", &bench.parse, baseline.map(|baseline| &baseline.parse)),
+    ("solve", &bench.solve, baseline.map(|baseline| &baseline.solve)),
+  ] {
+    output += &format!(
+      "{}  {:>10}  {:>10}  {:>10}  {:>10}",
+      name,
+      nanos(stats.min as f64),
+      nanos(stats.median as f64),
+      nanos(stats.mean),
+      nanos(stats.std_dev)
+    );
+
+    if let Some(baseline) = baseline {
+      output += &format!(
+        "  median {} vs {}",
+        change(stats.median, baseline.median),
+        nanos(baseline.median as f64)
+      );
+    }
+
+    output += "\n";
+  }
+
+  if let Some(allocations) = bench.allocations {
+    output += &format!(
+      "alloc  {} allocations, {} allocated, {} peak\n",
+      allocations.count,
+      format_bytes(allocations.bytes),
+      format_bytes(allocations.peak)
+    );
+  }
+
+  output
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_stats() {
+    let samples: Vec<_> = [4, 2, 8, 6].iter().map(|&ms| Duration::from_millis(ms)).collect();
+    let stats = Stats::from_samples(&samples);
+
+    assert_eq!(stats.min, 2_000_000);
+    assert_eq!(stats.median, 5_000_000);
+    assert_eq!(stats.mean, 5_000_000.0);
+    assert_eq!(stats.std_dev.round(), 2_236_068.0);
+  }
+
+  #[test]
+  fn test_change() {
+    assert_eq!(change(90, 100), "-10.0%");
+    assert_eq!(change(125, 100), "+25.0%");
+  }
+}
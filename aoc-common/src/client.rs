@@ -0,0 +1,272 @@
+use crypt;
+use regex::Regex;
+use solution::Part;
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use ureq;
+
+/// Environment variable holding the session cookie used to talk to the puzzle site.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// Environment variable overriding the puzzle site, e.g. to point at a local server.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+/// Environment variable overriding the User-Agent sent with every request.
+pub const USER_AGENT_VAR: &str = "AOC_USER_AGENT";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const DEFAULT_USER_AGENT: &str = concat!("aoc-rust/", env!("CARGO_PKG_VERSION"));
+
+#[derive(Debug)]
+pub enum ClientError {
+  Io(PathBuf, io::Error),
+  Config(PathBuf, String),
+  MissingSession,
+  Request(String),
+  Status(String, u16),
+  UnexpectedResponse(String),
+}
+
+impl fmt::Display for ClientError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      ClientError::Io(path, error) => write!(f, "could not access {}: {}", path.display(), error),
+      ClientError::Config(path, error) => write!(f, "could not parse {}: {}", path.display(), error),
+      ClientError::MissingSession => write!(f, "no session token, set {} or `session` in the config file", SESSION_VAR),
+      ClientError::Request(error) => write!(f, "request failed: {}", error),
+      ClientError::Status(url, status) => write!(f, "request to {} failed with status {}", url, status),
+      ClientError::UnexpectedResponse(url) => write!(f, "could not understand the response from {}", url),
+    }
+  }
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct ConfigFile {
+  session: Option<String>,
+  base_url: Option<String>,
+  user_agent: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+  pub session: Option<String>,
+  pub base_url: String,
+  pub user_agent: String,
+}
+
+impl Config {
+  /// Reads the config file at `path`, which may not exist, with the environment taking precedence.
+  pub fn load(path: &Path) -> Result<Config, ClientError> {
+    let file: ConfigFile = match fs::read_to_string(path) {
+      Ok(contents) => toml::from_str(&contents)
+        .map_err(|error| ClientError::Config(path.to_path_buf(), error.to_string()))?,
+      Err(ref error) if error.kind() == io::ErrorKind::NotFound => ConfigFile::default(),
+      Err(error) => return Err(ClientError::Io(path.to_path_buf(), error)),
+    };
+
+    Ok(Config {
+      session: env::var(SESSION_VAR).ok().or(file.session),
+      base_url: env::var(BASE_URL_VAR)
+        .ok()
+        .or(file.base_url)
+        .unwrap_or_else(|| DEFAULT_BASE_URL.to_string()),
+      user_agent: env::var(USER_AGENT_VAR)
+        .ok()
+        .or(file.user_agent)
+        .unwrap_or_else(|| DEFAULT_USER_AGENT.to_string()),
+    })
+  }
+}
+
+/// A client for the puzzle site.
+pub struct Client {
+  config: Config,
+  agent: ureq::Agent,
+}
+
+impl Client {
+  pub fn new(config: Config) -> Client {
+    let agent = ureq::AgentBuilder::new()
+      .user_agent(&config.user_agent)
+      .build();
+
+    Client { config, agent }
+  }
+
+  fn url(&self, year: u32, day: u32, path: &str) -> String {
+    format!(
+      "{}/{}/day/{}{}",
+      self.config.base_url.trim_end_matches('/'),
+      year,
+      day,
+      path
+    )
+  }
+
+  fn session(&self) -> Result<String, ClientError> {
+    self
+      .config
+      .session
+      .as_ref()
+      .map(|session| format!("session={}", session))
+      .ok_or(ClientError::MissingSession)
+  }
+
+  /// Downloads the puzzle input of `day` in `year`.
+  pub fn input(&self, year: u32, day: u32) -> Result<String, ClientError> {
+    let url = self.url(year, day, "/input");
+    let response = self
+      .agent
+      .get(&url)
+      .set("Cookie", &self.session()?)
+      .call()
+      .map_err(|error| match error {
+        ureq::Error::Status(status, _) => ClientError::Status(url.clone(), status),
+        error => ClientError::Request(error.to_string()),
+      })?;
+
+    response
+      .into_string()
+      .map_err(|error| ClientError::Request(format!("{}: {}", url, error)))
+  }
+
+  /// Posts `answer` as the answer to `part` of `day` in `year`.
+  pub fn submit(&self, year: u32, day: u32, part: Part, answer: &str) -> Result<Submission, ClientError> {
+    let url = self.url(year, day, "/answer");
+    let level = match part {
+      Part::A => "1",
+      Part::B => "2",
+    };
+    let page = self
+      .agent
+      .post(&url)
+      .set("Cookie", &self.session()?)
+      .send_form(&[("level", level), ("answer", answer)])
+      .map_err(|error| match error {
+        ureq::Error::Status(status, _) => ClientError::Status(url.clone(), status),
+        error => ClientError::Request(error.to_string()),
+      })?
+      .into_string()
+      .map_err(|error| ClientError::Request(format!("{}: {}", url, error)))?;
+
+    parse_submission(&page).ok_or(ClientError::UnexpectedResponse(url))
+  }
+}
+
+/// What the puzzle site said about a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+  Correct,
+  TooHigh,
+  TooLow,
+  Wrong,
+}
+
+impl fmt::Display for Verdict {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      Verdict::Correct => write!(f, "correct"),
+      Verdict::TooHigh => write!(f, "too high"),
+      Verdict::TooLow => write!(f, "too low"),
+      Verdict::Wrong => write!(f, "wrong"),
+    }
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Submission {
+  Judged(Verdict),
+  /// Answered too soon after the previous answer, with how long is left to wait.
+  RateLimited(Duration),
+  /// The part is already solved, or part a isn't solved yet when submitting part b.
+  WrongLevel,
+}
+
+/// Reads the verdict out of the page the puzzle site answers a submission with.
+pub fn parse_submission(page: &str) -> Option<Submission> {
+  let wait = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+
+  if page.contains("That's the right answer") {
+    Some(Submission::Judged(Verdict::Correct))
+  } else if page.contains("That's not the right answer") {
+    Some(Submission::Judged(if page.contains("your answer is too high") {
+      Verdict::TooHigh
+    } else if page.contains("your answer is too low") {
+      Verdict::TooLow
+    } else {
+      Verdict::Wrong
+    }))
+  } else if page.contains("You gave an answer too recently") {
+    let seconds = wait.captures(page).map_or(0, |captures| {
+      let minutes: u64 = captures.get(1).map_or(0, |minutes| minutes.as_str().parse().unwrap());
+      let seconds: u64 = captures[2].parse().unwrap();
+
+      minutes * 60 + seconds
+    });
+
+    Some(Submission::RateLimited(Duration::from_secs(seconds)))
+  } else if page.contains("You don't seem to be solving the right level") {
+    Some(Submission::WrongLevel)
+  } else {
+    None
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fetched {
+  Downloaded,
+  Cached,
+}
+
+/// Downloads the input of `day` to `path`, unless it has been downloaded before.
+///
+/// An empty file, like the one `new` creates, doesn't count as downloaded, but
+/// an encrypted copy does.
+pub fn fetch_input(client: &Client, path: &Path, year: u32, day: u32) -> Result<Fetched, ClientError> {
+  if fs::metadata(path).is_ok_and(|metadata| metadata.len() > 0) || crypt::encrypted_path(path).exists() {
+    return Ok(Fetched::Cached);
+  }
+
+  let input = client.input(year, day)?;
+
+  if let Some(dir) = path.parent() {
+    fs::create_dir_all(dir).map_err(|error| ClientError::Io(dir.to_path_buf(), error))?;
+  }
+
+  fs::write(path, input).map_err(|error| ClientError::Io(path.to_path_buf(), error))?;
+
+  Ok(Fetched::Downloaded)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_parse_submission() {
+    assert_eq!(
+      parse_submission("<p>That's the right answer! You are one gold star closer.</p>"),
+      Some(Submission::Judged(Verdict::Correct))
+    );
+    assert_eq!(
+      parse_submission("<p>That's not the right answer; your answer is too low.</p>"),
+      Some(Submission::Judged(Verdict::TooLow))
+    );
+    assert_eq!(
+      parse_submission("<p>That's not the right answer. If you're stuck, ...</p>"),
+      Some(Submission::Judged(Verdict::Wrong))
+    );
+    assert_eq!(
+      parse_submission("<p>You gave an answer too recently. You have 1m 5s left to wait.</p>"),
+      Some(Submission::RateLimited(Duration::from_secs(65)))
+    );
+    assert_eq!(
+      parse_submission("<p>You gave an answer too recently. You have 38s left to wait.</p>"),
+      Some(Submission::RateLimited(Duration::from_secs(38)))
+    );
+    assert_eq!(parse_submission("<p>Something else</p>"), None);
+  }
+}
@@ -0,0 +1,157 @@
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{ChaCha20Poly1305, Nonce};
+use hex;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Environment variable overriding where the input key is kept.
+pub const KEY_VAR: &str = "AOC_INPUT_KEY";
+
+/// Extension of encrypted inputs, as in `day_07/a.input.enc`.
+pub const EXTENSION: &str = "enc";
+
+const NONCE_LENGTH: usize = 12;
+
+#[derive(Debug)]
+pub enum CryptError {
+  Io(PathBuf, io::Error),
+  InvalidKey(PathBuf),
+  KeyExists(PathBuf),
+  Decrypt(PathBuf),
+}
+
+impl fmt::Display for CryptError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      CryptError::Io(path, error) => write!(f, "could not access {}: {}", path.display(), error),
+      CryptError::InvalidKey(path) => write!(f, "{} does not hold a 32 byte hex encoded key", path.display()),
+      CryptError::KeyExists(path) => write!(f, "{} already exists", path.display()),
+      CryptError::Decrypt(path) => write!(f, "could not decrypt {}, is it encrypted with this key?", path.display()),
+    }
+  }
+}
+
+/// A ChaCha20-Poly1305 key, stored hex encoded in a key file.
+pub struct Key {
+  cipher: ChaCha20Poly1305,
+}
+
+impl Key {
+  pub fn load(path: &Path) -> Result<Key, CryptError> {
+    let contents = fs::read_to_string(path).map_err(|error| CryptError::Io(path.to_path_buf(), error))?;
+    let bytes = hex::decode(contents.trim()).map_err(|_| CryptError::InvalidKey(path.to_path_buf()))?;
+    let cipher = ChaCha20Poly1305::new_from_slice(&bytes).map_err(|_| CryptError::InvalidKey(path.to_path_buf()))?;
+
+    Ok(Key { cipher })
+  }
+
+  /// Generates a new key and saves it to `path`, which must not exist yet.
+  pub fn generate(path: &Path) -> Result<Key, CryptError> {
+    if path.exists() {
+      return Err(CryptError::KeyExists(path.to_path_buf()));
+    }
+
+    let key = ChaCha20Poly1305::generate_key(&mut OsRng);
+
+    fs::write(path, hex::encode(key) + "\n").map_err(|error| CryptError::Io(path.to_path_buf(), error))?;
+
+    Ok(Key {
+      cipher: ChaCha20Poly1305::new(&key),
+    })
+  }
+
+  /// Encrypts `plaintext` under a fresh nonce, which is prepended to the ciphertext.
+  pub fn encrypt(&self, plaintext: &[u8]) -> Vec<u8> {
+    let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let ciphertext = self
+      .cipher
+      .encrypt(&nonce, plaintext)
+      .expect("Inputs fit in a single message");
+
+    nonce.iter().cloned().chain(ciphertext).collect()
+  }
+
+  /// Decrypts what `encrypt` produced, or `None` if it was tampered with or uses another key.
+  pub fn decrypt(&self, encrypted: &[u8]) -> Option<Vec<u8>> {
+    if encrypted.len() < NONCE_LENGTH {
+      return None;
+    }
+
+    let (nonce, ciphertext) = encrypted.split_at(NONCE_LENGTH);
+
+    self.cipher.decrypt(Nonce::from_slice(nonce), ciphertext).ok()
+  }
+}
+
+/// The encrypted counterpart of `path`, e.g. `a.input.enc` for `a.input`.
+pub fn encrypted_path(path: &Path) -> PathBuf {
+  let mut encrypted = path.as_os_str().to_owned();
+  encrypted.push(".");
+  encrypted.push(EXTENSION);
+
+  PathBuf::from(encrypted)
+}
+
+pub fn is_encrypted(path: &Path) -> bool {
+  path.extension().is_some_and(|extension| extension == EXTENSION)
+}
+
+/// Encrypts the file at `path` into its `.enc` counterpart.
+pub fn encrypt_file(key: &Key, path: &Path) -> Result<PathBuf, CryptError> {
+  let plaintext = fs::read(path).map_err(|error| CryptError::Io(path.to_path_buf(), error))?;
+  let encrypted = encrypted_path(path);
+
+  fs::write(&encrypted, key.encrypt(&plaintext)).map_err(|error| CryptError::Io(encrypted.clone(), error))?;
+
+  Ok(encrypted)
+}
+
+/// Decrypts the `.enc` file at `path` into the file without the extension.
+pub fn decrypt_file(key: &Key, path: &Path) -> Result<PathBuf, CryptError> {
+  let encrypted = fs::read(path).map_err(|error| CryptError::Io(path.to_path_buf(), error))?;
+  let plaintext = key
+    .decrypt(&encrypted)
+    .ok_or_else(|| CryptError::Decrypt(path.to_path_buf()))?;
+  let decrypted = path.with_extension("");
+
+  fs::write(&decrypted, plaintext).map_err(|error| CryptError::Io(decrypted.clone(), error))?;
+
+  Ok(decrypted)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::env;
+
+  #[test]
+  fn test_round_trip() {
+    let dir = env::temp_dir().join(format!("aoc-crypt-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+
+    let key = Key::generate(&dir.join("input.key")).unwrap();
+    let other = Key::generate(&dir.join("other.key")).unwrap();
+    let reloaded = Key::load(&dir.join("input.key")).unwrap();
+    let exists = Key::generate(&dir.join("input.key")).is_err();
+    fs::remove_dir_all(&dir).unwrap();
+
+    let encrypted = key.encrypt(b"1 2 3\n");
+
+    assert!(exists);
+    assert_eq!(encrypted.len(), NONCE_LENGTH + 6 + 16);
+    assert_eq!(reloaded.decrypt(&encrypted), Some(b"1 2 3\n".to_vec()));
+    assert_eq!(other.decrypt(&encrypted), None);
+    assert_ne!(key.encrypt(b"1 2 3\n"), encrypted);
+  }
+
+  #[test]
+  fn test_encrypted_path() {
+    let path = encrypted_path(Path::new("src/day_07/a.input"));
+
+    assert_eq!(path, PathBuf::from("src/day_07/a.input.enc"));
+    assert!(is_encrypted(&path));
+    assert_eq!(path.with_extension(""), PathBuf::from("src/day_07/a.input"));
+  }
+}
@@ -0,0 +1,64 @@
+use std::error::Error;
+use std::fmt;
+
+/// An input that a day could not parse, pointing at where in the input it went wrong.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+  pub day: u32,
+  /// The line number, starting at 1.
+  pub line: usize,
+  /// The column in characters, starting at 1.
+  pub column: usize,
+  /// The offending line.
+  pub snippet: String,
+  pub message: String,
+}
+
+impl ParseError {
+  /// An error at byte `offset` of `snippet`, which is line `line` of the input.
+  pub fn new(day: u32, line: usize, snippet: &str, offset: usize, message: impl Into<String>) -> ParseError {
+    ParseError {
+      day,
+      line,
+      column: snippet[..offset.min(snippet.len())].chars().count() + 1,
+      snippet: snippet.to_string(),
+      message: message.into(),
+    }
+  }
+}
+
+impl fmt::Display for ParseError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    let gutter = " ".repeat(self.line.to_string().len());
+
+    writeln!(
+      f,
+      "day {} input, line {}, column {}: {}",
+      self.day, self.line, self.column, self.message
+    )?;
+    writeln!(f, "{} |", gutter)?;
+    writeln!(f, "{} | {}", self.line, self.snippet)?;
+    write!(f, "{} | {}^", gutter, " ".repeat(self.column - 1))
+  }
+}
+
+impl Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_display() {
+    let error = ParseError::new(3, 12, "#2 @ 3,1 4x4", 8, "expected \":\"");
+
+    assert_eq!(error.column, 9);
+    assert_eq!(
+      error.to_string(),
+      "day 3 input, line 12, column 9: expected \":\"
+   |
+12 | #2 @ 3,1 4x4
+   |         ^"
+    );
+  }
+}
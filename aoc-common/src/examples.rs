@@ -0,0 +1,297 @@
+use regex::Regex;
+use solution::Part;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug)]
+pub enum ExamplesError {
+  MissingDay(PathBuf),
+  NoExamples(PathBuf),
+  NoAnswers(PathBuf, Part),
+  Io(PathBuf, io::Error),
+}
+
+impl fmt::Display for ExamplesError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      ExamplesError::MissingDay(path) => write!(f, "{} does not exist, create it with `new` first", path.display()),
+      ExamplesError::NoExamples(path) => write!(f, "found no <pre><code> blocks in {}", path.display()),
+      ExamplesError::NoAnswers(path, part) => {
+        write!(f, "found no emphasized answers after an example in part {} of {}", part, path.display())
+      }
+      ExamplesError::Io(path, error) => write!(f, "could not access {}: {}", path.display(), error),
+    }
+  }
+}
+
+/// The examples of a saved puzzle page and the answers the page gives for them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Page {
+  pub examples: Vec<String>,
+  /// The answers in each part's article, with the index of the last example
+  /// before each, which for part b is often one of part a's.
+  pub answers: Vec<Vec<(usize, String)>>,
+}
+
+fn unescape(html: &str) -> String {
+  let tags = Regex::new(r"<[^>]*>").unwrap();
+
+  tags
+    .replace_all(html, "")
+    .replace("&lt;", "<")
+    .replace("&gt;", ">")
+    .replace("&quot;", "\"")
+    .replace("&#39;", "'")
+    .replace("&amp;", "&")
+}
+
+/// Picks the `<pre><code>` blocks and the `<code><em>` answers out of a puzzle page.
+///
+/// Of the values emphasized after the same example within one part's
+/// `<article>`, the last is taken as the answer.
+pub fn extract(html: &str) -> Page {
+  let articles = Regex::new(r"(?s)<article[^>]*>(.*?)</article>").unwrap();
+  let tokens =
+    Regex::new(r"(?s)<pre><code>(.*?)</code></pre>|<code><em>(.*?)</em></code>|<em><code>(.*?)</code></em>").unwrap();
+
+  let mut articles: Vec<_> = articles
+    .captures_iter(html)
+    .map(|captures| captures.get(1).unwrap().as_str())
+    .collect();
+
+  if articles.is_empty() {
+    articles.push(html);
+  }
+
+  let mut examples = vec![];
+  let mut answers = vec![];
+
+  for article in articles {
+    let mut part_answers: Vec<(usize, String)> = vec![];
+
+    for captures in tokens.captures_iter(article) {
+      if let Some(block) = captures.get(1) {
+        examples.push(unescape(block.as_str()));
+        continue;
+      }
+
+      let answer = unescape(captures.get(2).or_else(|| captures.get(3)).unwrap().as_str());
+      let example = match examples.len().checked_sub(1) {
+        Some(example) => example,
+        None => continue,
+      };
+
+      match part_answers.last_mut() {
+        Some((answered, previous)) if *answered == example => *previous = answer,
+        _ => part_answers.push((example, answer)),
+      }
+    }
+
+    answers.push(part_answers);
+  }
+
+  Page { examples, answers }
+}
+
+/// Tests checking that `part` of `day` gives each answer of the page.
+///
+/// Pages can show other blocks, like diagrams, between an example and its
+/// answer, so each test uses the nearest example before the answer that the
+/// day can parse.
+fn render_tests(day: u32, part: Part, answers: &[(usize, String)]) -> String {
+  let mut tests = format!(
+    "// Generated by `aoc examples {day} <page> {part}` from the saved puzzle page.
+use super::Day{day:02};
+use solution::{{solve, Part}};
+
+/// The answer for the first of `examples` that parses.
+fn answer(examples: &[&str]) -> String {{
+  let solved = examples
+    .iter()
+    .find_map(|example| solve::<Day{day:02}>(example, &[Part::{variant}]).ok())
+    .expect(\"None of the examples parse\");
+
+  solved.answers[0].1.to_string()
+}}
+",
+    day = day,
+    part = part,
+    variant = part.to_string().to_uppercase(),
+  );
+
+  for (i, (example, answer)) in answers.iter().enumerate() {
+    let examples: Vec<_> = (0..=*example)
+      .rev()
+      .map(|example| format!("include_str!(\"examples/example_{}.txt\")", example + 1))
+      .collect();
+
+    tests += &format!(
+      "
+#[test]
+fn test_answer_{number}() {{
+  let examples = [
+    {examples},
+  ];
+
+  assert_eq!(answer(&examples), {answer:?});
+}}
+",
+      number = i + 1,
+      examples = examples.join(",\n    "),
+      answer = answer
+    );
+  }
+
+  tests
+}
+
+/// Declares the test module `name` in the day's `mod.rs`, after its other modules.
+fn register(module: &str, name: &str) -> String {
+  if module.contains(&format!("mod {};", name)) {
+    return module.to_string();
+  }
+
+  let mut lines: Vec<&str> = module.lines().collect();
+  let position = lines
+    .iter()
+    .rposition(|line| line.starts_with("pub mod ") || line.starts_with("mod examples_"))
+    .map_or(0, |i| i + 1);
+  let declaration = format!("#[cfg(test)]\nmod {};", name);
+
+  lines.insert(position, &declaration);
+
+  lines.join("\n") + "\n"
+}
+
+/// Writes the examples of `page` to `day_NN/examples/` in `src`, with tests
+/// for `part` in `day_NN/examples_a.rs` or `examples_b.rs`.
+pub fn write_examples(src: &Path, day: u32, page: &Path, part: Part) -> Result<Vec<PathBuf>, ExamplesError> {
+  let dir = src.join(format!("day_{:02}", day));
+
+  if !dir.exists() {
+    return Err(ExamplesError::MissingDay(dir));
+  }
+
+  let html = fs::read_to_string(page).map_err(|error| ExamplesError::Io(page.to_path_buf(), error))?;
+  let Page { examples, answers } = extract(&html);
+
+  if examples.is_empty() {
+    return Err(ExamplesError::NoExamples(page.to_path_buf()));
+  }
+
+  let index = match part {
+    Part::A => 0,
+    Part::B => 1,
+  };
+  let answers = match answers.get(index) {
+    Some(answers) if !answers.is_empty() => answers,
+    _ => return Err(ExamplesError::NoAnswers(page.to_path_buf(), part)),
+  };
+
+  let examples_dir = dir.join("examples");
+  fs::create_dir_all(&examples_dir).map_err(|error| ExamplesError::Io(examples_dir.clone(), error))?;
+
+  let mut written = vec![];
+  let mut write = |path: PathBuf, contents: String| {
+    fs::write(&path, contents).map_err(|error| ExamplesError::Io(path.clone(), error))?;
+    written.push(path);
+    Ok(())
+  };
+
+  for (i, example) in examples.into_iter().enumerate() {
+    write(examples_dir.join(format!("example_{}.txt", i + 1)), example)?;
+  }
+
+  let name = format!("examples_{}", part);
+  write(dir.join(format!("{}.rs", name)), render_tests(day, part, answers))?;
+
+  let module = dir.join("mod.rs");
+  let contents = fs::read_to_string(&module).map_err(|error| ExamplesError::Io(module.clone(), error))?;
+  write(module, register(&contents, &name))?;
+
+  Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const PAGE: &str = "<article class=\"day-desc\"><h2>--- Day 7: The Sum of Its Parts ---</h2>
+<p>For example, suppose you have the following instructions:</p>
+<pre><code>Step C must be finished before step A can begin.
+Step C must be finished before step F can begin.
+Step A must be finished before step B can begin.
+Step A must be finished before step D can begin.
+Step B must be finished before step E can begin.
+Step D must be finished before step E can begin.
+Step F must be finished before step E can begin.
+</code></pre>
+<p>Visually, these requirements look like this:</p>
+<pre><code>  --&gt;A---&gt;B--
+ /    \\      \\
+C      --&gt;D-----&gt;E
+ \\           /
+  ----&gt;F-----
+</code></pre>
+<p>The first step is <code><em>C</em></code>, so the correct order is <code><em>CABDFE</em></code>.</p>
+</article>
+<article class=\"day-desc\"><h2>--- Part Two ---</h2>
+<p>In the example above, it takes <em><code>15</code></em> seconds.</p>
+</article>";
+
+  #[test]
+  fn test_extract() {
+    let page = extract(PAGE);
+
+    assert_eq!(page.examples.len(), 2);
+    assert!(page.examples[0].starts_with("Step C must be finished before step A can begin.\n"));
+    assert!(page.examples[1].starts_with("  -->A--->B--\n"));
+    assert_eq!(
+      page.answers,
+      vec![vec![(1, "CABDFE".to_string())], vec![(1, "15".to_string())]]
+    );
+  }
+
+  #[test]
+  fn test_extract_without_articles() {
+    let page = extract("<pre><code>1\n</code></pre><code><em>2</em></code><pre><code>3\n</code></pre><em><code>4</code></em>");
+
+    assert_eq!(page.answers, vec![vec![(0, "2".to_string()), (1, "4".to_string())]]);
+  }
+
+  #[test]
+  fn test_render_tests() {
+    let tests = render_tests(7, Part::B, &[(1, "15".to_string())]);
+
+    assert!(tests.contains(".find_map(|example| solve::<Day07>(example, &[Part::B]).ok())"));
+    assert!(tests.contains(
+      "fn test_answer_1() {
+  let examples = [
+    include_str!(\"examples/example_2.txt\"),
+    include_str!(\"examples/example_1.txt\"),
+  ];
+
+  assert_eq!(answer(&examples), \"15\");
+}
+"
+    ));
+  }
+
+  #[test]
+  fn test_register() {
+    let module = "pub mod a;\npub mod b;\n\nuse aoc_macros::aoc;\n";
+    let registered = register(module, "examples_a");
+
+    assert_eq!(
+      registered,
+      "pub mod a;\npub mod b;\n#[cfg(test)]\nmod examples_a;\n\nuse aoc_macros::aoc;\n"
+    );
+    assert_eq!(register(&registered, "examples_a"), registered);
+    assert_eq!(
+      register(&registered, "examples_b"),
+      "pub mod a;\npub mod b;\n#[cfg(test)]\nmod examples_a;\n#[cfg(test)]\nmod examples_b;\n\nuse aoc_macros::aoc;\n"
+    );
+  }
+}
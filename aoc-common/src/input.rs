@@ -0,0 +1,227 @@
+use crypt::{self, Key};
+use std::env;
+use std::fmt;
+use std::fs::{self, File};
+use std::io;
+use std::io::prelude::*;
+use std::path::{Path, PathBuf};
+
+/// Environment variable naming an input file to use instead of the inputs directory.
+pub const INPUT_VAR: &str = "AOC_INPUT";
+/// Environment variable overriding the default inputs directory.
+pub const INPUTS_DIR_VAR: &str = "AOC_INPUTS_DIR";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+  Path(PathBuf),
+  /// An input encrypted with the key at `key`.
+  Encrypted { path: PathBuf, key: PathBuf },
+  Stdin,
+}
+
+impl Source {
+  fn from_arg(arg: &str, key: &Path) -> Source {
+    match arg {
+      "-" => Source::Stdin,
+      path if crypt::is_encrypted(Path::new(path)) => Source::Encrypted {
+        path: PathBuf::from(path),
+        key: key.to_path_buf(),
+      },
+      path => Source::Path(PathBuf::from(path)),
+    }
+  }
+
+  pub fn read(&self) -> Result<String, InputError> {
+    let mut contents = String::new();
+
+    let result = match self {
+      Source::Path(path) => File::open(path).and_then(|mut f| f.read_to_string(&mut contents).map(|_| ())),
+      Source::Encrypted { path, key } => decrypt(path, key).map(|decrypted| contents = decrypted),
+      Source::Stdin => io::stdin().read_to_string(&mut contents).map(|_| ()),
+    };
+
+    result.map(|_| contents).map_err(|error| InputError {
+      source: self.clone(),
+      error,
+    })
+  }
+}
+
+fn decrypt(path: &Path, key_path: &Path) -> io::Result<String> {
+  let invalid = |error: String| io::Error::new(io::ErrorKind::InvalidData, error);
+  let key = Key::load(key_path).map_err(|error| invalid(error.to_string()))?;
+  let decrypted = key
+    .decrypt(&fs::read(path)?)
+    .ok_or_else(|| invalid(format!("it is not encrypted with {}", key_path.display())))?;
+
+  String::from_utf8(decrypted).map_err(|error| invalid(error.to_string()))
+}
+
+impl fmt::Display for Source {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      Source::Path(path) | Source::Encrypted { path, .. } => write!(f, "{}", path.display()),
+      Source::Stdin => write!(f, "<stdin>"),
+    }
+  }
+}
+
+#[derive(Debug)]
+pub struct InputError {
+  pub source: Source,
+  pub error: io::Error,
+}
+
+impl fmt::Display for InputError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "could not read {}: {}", self.source, self.error)
+  }
+}
+
+/// Decides where each day's input is read from.
+///
+/// An explicit input (a path, or `-` for stdin) wins over `AOC_INPUT`, which
+/// wins over `day_NN/a.input` in the inputs directory. When only the encrypted
+/// `day_NN/a.input.enc` is there, it is decrypted with the key.
+///
+/// The inputs directory and key come from the arguments, then the environment,
+/// then the defaults, usually the year's source directory and a key shared by
+/// all years.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Inputs {
+  input: Option<Source>,
+  dir: PathBuf,
+  key: PathBuf,
+}
+
+impl Inputs {
+  pub fn new(
+    input: Option<&str>,
+    dir: Option<&Path>,
+    key: Option<&Path>,
+    default_dir: &Path,
+    default_key: &Path,
+  ) -> Inputs {
+    let input = input
+      .map(str::to_string)
+      .or_else(|| env::var(INPUT_VAR).ok());
+    let dir = dir
+      .map(Path::to_path_buf)
+      .or_else(|| env::var_os(INPUTS_DIR_VAR).map(PathBuf::from))
+      .unwrap_or_else(|| default_dir.to_path_buf());
+    let key = key
+      .map(Path::to_path_buf)
+      .or_else(|| env::var_os(crypt::KEY_VAR).map(PathBuf::from))
+      .unwrap_or_else(|| default_key.to_path_buf());
+
+    Inputs {
+      input: input.map(|input| Source::from_arg(&input, &key)),
+      dir,
+      key,
+    }
+  }
+
+  pub fn key(&self) -> &Path {
+    &self.key
+  }
+
+  /// Whether every day would be given the same explicit input.
+  pub fn is_explicit(&self) -> bool {
+    self.input.is_some()
+  }
+
+  /// Where the input of `day` lives in the inputs directory.
+  pub fn path(&self, day: u32) -> PathBuf {
+    self.dir.join(format!("day_{:02}", day)).join("a.input")
+  }
+
+  pub fn source(&self, day: u32) -> Source {
+    let path = self.path(day);
+    let encrypted = crypt::encrypted_path(&path);
+
+    match &self.input {
+      Some(source) => source.clone(),
+      None if !path.exists() && encrypted.exists() => Source::Encrypted {
+        path: encrypted,
+        key: self.key.clone(),
+      },
+      None => Source::Path(path),
+    }
+  }
+
+  pub fn load(&self, day: u32) -> Result<String, InputError> {
+    self.source(day).read()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_source_from_inputs_dir() {
+    let inputs = Inputs {
+      input: None,
+      dir: PathBuf::from("inputs"),
+      key: PathBuf::from("input.key"),
+    };
+
+    assert_eq!(
+      inputs.source(7),
+      Source::Path(PathBuf::from("inputs/day_07/a.input"))
+    );
+  }
+
+  #[test]
+  fn test_encrypted_source() {
+    let dir = env::temp_dir().join(format!("aoc-inputs-{}", std::process::id()));
+    fs::create_dir_all(dir.join("day_07")).unwrap();
+    fs::write(dir.join("day_07/a.input.enc"), "").unwrap();
+
+    let inputs = Inputs {
+      input: None,
+      dir: dir.clone(),
+      key: PathBuf::from("input.key"),
+    };
+    let encrypted = inputs.source(7);
+    fs::write(dir.join("day_07/a.input"), "").unwrap();
+    let plain = inputs.source(7);
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(
+      encrypted,
+      Source::Encrypted {
+        path: dir.join("day_07/a.input.enc"),
+        key: PathBuf::from("input.key"),
+      }
+    );
+    assert_eq!(plain, Source::Path(dir.join("day_07/a.input")));
+  }
+
+  #[test]
+  fn test_explicit_source() {
+    let key = Path::new("input.key");
+
+    assert_eq!(Source::from_arg("-", key), Source::Stdin);
+    assert_eq!(
+      Source::from_arg("day7.txt", key),
+      Source::Path(PathBuf::from("day7.txt"))
+    );
+    assert_eq!(
+      Source::from_arg("day7.txt.enc", key),
+      Source::Encrypted {
+        path: PathBuf::from("day7.txt.enc"),
+        key: key.to_path_buf(),
+      }
+    );
+  }
+
+  #[test]
+  fn test_error_names_path() {
+    let error = Source::Path(PathBuf::from("missing/a.input"))
+      .read()
+      .unwrap_err();
+
+    assert!(error.to_string().starts_with("could not read missing/a.input: "));
+  }
+}
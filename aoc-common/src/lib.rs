@@ -0,0 +1,31 @@
+extern crate chacha20poly1305;
+extern crate hex;
+pub extern crate inventory;
+extern crate regex;
+#[macro_use]
+extern crate serde;
+extern crate serde_json;
+extern crate toml;
+#[macro_use]
+extern crate tracing;
+extern crate tracing_subscriber;
+extern crate ureq;
+
+pub mod answers;
+pub mod bench;
+pub mod client;
+pub mod crypt;
+pub mod error;
+pub mod examples;
+pub mod input;
+pub mod logging;
+pub mod memory;
+pub mod registry;
+pub mod report;
+pub mod runner;
+pub mod scan;
+pub mod scaffold;
+pub mod solution;
+pub mod submit;
+pub mod text;
+pub mod watch;
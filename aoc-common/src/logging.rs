@@ -0,0 +1,47 @@
+use std::io::{self, IsTerminal};
+use tracing::level_filters::LevelFilter;
+use tracing_subscriber::fmt::format::FmtSpan;
+
+/// The level to log at, given the number of `-v` flags minus the number of `-q` flags.
+///
+/// Warnings are shown by default, `-v` adds how long each parse and solve span
+/// took, `-vv` the solutions' debug output and `-vvv` their traces.
+pub fn level(verbosity: i32) -> LevelFilter {
+  match verbosity {
+    i32::MIN..=-1 => LevelFilter::ERROR,
+    0 => LevelFilter::WARN,
+    1 => LevelFilter::INFO,
+    2 => LevelFilter::DEBUG,
+    _ => LevelFilter::TRACE,
+  }
+}
+
+/// Logs to stderr, so stdout only carries answers.
+pub fn init(level: LevelFilter) {
+  let span_events = if level >= LevelFilter::INFO {
+    FmtSpan::CLOSE
+  } else {
+    FmtSpan::NONE
+  };
+
+  tracing_subscriber::fmt()
+    .with_max_level(level)
+    .with_span_events(span_events)
+    .with_target(false)
+    .with_ansi(io::stderr().is_terminal())
+    .with_writer(io::stderr)
+    .init();
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_level() {
+    assert_eq!(level(-2), LevelFilter::ERROR);
+    assert_eq!(level(0), LevelFilter::WARN);
+    assert_eq!(level(2), LevelFilter::DEBUG);
+    assert_eq!(level(5), LevelFilter::TRACE);
+  }
+}
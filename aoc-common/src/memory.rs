@@ -0,0 +1,139 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+/// Whether allocations are counted, which needs the `alloc-stats` feature.
+pub const ENABLED: bool = cfg!(feature = "alloc-stats");
+
+/// What a piece of code allocated on the thread that ran it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Allocations {
+  /// Number of allocations, counting a reallocation as one.
+  pub count: u64,
+  /// Bytes requested over all allocations.
+  pub bytes: u64,
+  /// The most bytes live at once, on top of what was live before.
+  pub peak: u64,
+}
+
+#[derive(Clone, Copy)]
+struct Counters {
+  count: u64,
+  bytes: u64,
+  live: u64,
+  peak: u64,
+}
+
+thread_local! {
+  static COUNTERS: Cell<Counters> = const {
+    Cell::new(Counters {
+      count: 0,
+      bytes: 0,
+      live: 0,
+      peak: 0,
+    })
+  };
+}
+
+fn record(allocated: usize, freed: usize) {
+  // The counters are gone while a thread shuts down, its last frees go uncounted.
+  let _ = COUNTERS.try_with(|counters| {
+    let mut current = counters.get();
+
+    if allocated > 0 {
+      current.count += 1;
+      current.bytes += allocated as u64;
+    }
+    current.live = (current.live + allocated as u64).saturating_sub(freed as u64);
+    current.peak = current.peak.max(current.live);
+
+    counters.set(current);
+  });
+}
+
+/// The system allocator, counting what each thread allocates.
+pub struct Counting;
+
+unsafe impl GlobalAlloc for Counting {
+  unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+    record(layout.size(), 0);
+    System.alloc(layout)
+  }
+
+  unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+    record(layout.size(), 0);
+    System.alloc_zeroed(layout)
+  }
+
+  unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+    record(0, layout.size());
+    System.dealloc(ptr, layout)
+  }
+
+  unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+    record(new_size, layout.size());
+    System.realloc(ptr, layout, new_size)
+  }
+}
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+/// Runs `f`, counting what it allocates when counting is enabled.
+///
+/// Only allocations on the current thread are seen, which is where solutions run.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Allocations>) {
+  if !ENABLED {
+    return (f(), None);
+  }
+
+  let start = COUNTERS.with(|counters| {
+    let mut start = counters.get();
+    start.peak = start.live;
+    counters.set(start);
+    start
+  });
+
+  let value = f();
+  let end = COUNTERS.with(Cell::get);
+
+  let allocations = Allocations {
+    count: end.count - start.count,
+    bytes: end.bytes - start.bytes,
+    peak: end.peak - start.live,
+  };
+
+  (value, Some(allocations))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  #[cfg(not(feature = "alloc-stats"))]
+  fn test_measure_disabled() {
+    assert_eq!(measure(|| vec![1, 2, 3].len()), (3, None));
+  }
+
+  #[test]
+  #[cfg(feature = "alloc-stats")]
+  fn test_measure() {
+    let (length, allocations) = measure(|| {
+      let first: Vec<u8> = Vec::with_capacity(1000);
+      drop(first);
+      let second: Vec<u8> = Vec::with_capacity(400);
+      second.capacity()
+    });
+
+    assert_eq!(length, 400);
+    assert_eq!(
+      allocations,
+      Some(Allocations {
+        count: 2,
+        bytes: 1400,
+        peak: 1000,
+      })
+    );
+  }
+}
@@ -0,0 +1,48 @@
+use solution::{Part, Solve};
+
+/// The parts of a day that one `Solution` impl registers with `#[aoc(day = N)]`.
+#[derive(Debug)]
+pub struct Registration {
+  pub year: u32,
+  pub day: u32,
+  pub parts: &'static [Part],
+  pub solve: Solve,
+}
+
+inventory::collect!(Registration);
+
+/// Every registered solution of `year` with the parts it solves, ordered by day and part.
+///
+/// A day whose parts are registered on different impls is listed once per impl,
+/// and the parts of one impl share a parse of the input.
+pub fn days(year: u32) -> Vec<(u32, Solve, Vec<Part>)> {
+  let mut solutions: Vec<_> = inventory::iter::<Registration>
+    .into_iter()
+    .filter(|registration| registration.year == year)
+    .map(|registration| {
+      let mut parts = registration.parts.to_vec();
+      parts.sort();
+
+      (registration.day, registration.solve, parts)
+    }).collect();
+  solutions.sort_by_key(|(day, _, parts)| (*day, parts.first().cloned()));
+
+  solutions
+}
+
+/// The registered solutions of `day`, empty if it isn't implemented.
+pub fn day(year: u32, day: u32) -> Vec<(u32, Solve, Vec<Part>)> {
+  days(year).into_iter().filter(|&(d, _, _)| d == day).collect()
+}
+
+/// Every year with at least one registered day, in order.
+pub fn years() -> Vec<u32> {
+  let mut years: Vec<_> = inventory::iter::<Registration>
+    .into_iter()
+    .map(|registration| registration.year)
+    .collect();
+  years.sort_unstable();
+  years.dedup();
+
+  years
+}
@@ -0,0 +1,417 @@
+use memory::Allocations;
+use serde_json;
+use solution::{Answer, Part, Solved};
+use std::fmt::{self, Write};
+use std::time::Duration;
+
+/// One line of the run-all table.
+///
+/// The parts a solution solves share one parse, so only the first of them
+/// carries its parse time.
+#[derive(Debug)]
+pub struct Row {
+  pub day: u32,
+  pub part: Part,
+  pub answer: Answer,
+  pub parse_time: Option<Duration>,
+  pub solve_time: Duration,
+  pub allocations: Option<Allocations>,
+}
+
+impl Row {
+  pub fn from_solved(day: u32, solved: Solved) -> Vec<Row> {
+    let mut parse_time = Some(solved.parse_time);
+
+    solved
+      .answers
+      .into_iter()
+      .zip(solved.allocations)
+      .map(|((part, answer, solve_time), allocations)| Row {
+        day,
+        part,
+        answer,
+        parse_time: parse_time.take(),
+        solve_time,
+        allocations,
+      }).collect()
+  }
+
+  pub fn total_time(&self) -> Duration {
+    self.parse_time.unwrap_or_default() + self.solve_time
+  }
+}
+
+pub fn format_duration(duration: Duration) -> String {
+  let nanos = duration.as_nanos();
+
+  if nanos < 1_000 {
+    format!("{} ns", nanos)
+  } else if nanos < 1_000_000 {
+    format!("{:.1} µs", nanos as f64 / 1e3)
+  } else if nanos < 1_000_000_000 {
+    format!("{:.1} ms", nanos as f64 / 1e6)
+  } else {
+    format!("{:.2} s", nanos as f64 / 1e9)
+  }
+}
+
+pub fn format_bytes(bytes: u64) -> String {
+  if bytes < 1 << 10 {
+    format!("{} B", bytes)
+  } else if bytes < 1 << 20 {
+    format!("{:.1} KiB", bytes as f64 / (1 << 10) as f64)
+  } else if bytes < 1 << 30 {
+    format!("{:.1} MiB", bytes as f64 / (1 << 20) as f64)
+  } else {
+    format!("{:.2} GiB", bytes as f64 / (1 << 30) as f64)
+  }
+}
+
+const HEADER: [&str; 6] = ["Day", "Part", "Answer", "Parse", "Solve", "Total"];
+
+/// Extra columns for when allocations are counted.
+const ALLOCATIONS_HEADER: [&str; 3] = ["Allocs", "Allocated", "Peak"];
+
+/// The columns that are left aligned, all others are numbers.
+fn is_left_aligned(column: usize) -> bool {
+  column == 1 || column == 2
+}
+
+struct Cells {
+  header: Vec<&'static str>,
+  rows: Vec<Vec<String>>,
+  total: String,
+}
+
+fn cells(rows: &[Row]) -> Cells {
+  let with_allocations = rows.iter().any(|row| row.allocations.is_some());
+  let mut header = HEADER.to_vec();
+
+  if with_allocations {
+    header.extend(ALLOCATIONS_HEADER);
+  }
+
+  let cells = rows
+    .iter()
+    .map(|row| {
+      let mut cells = vec![
+        row.day.to_string(),
+        row.part.to_string(),
+        row.answer.to_string(),
+        row.parse_time.map(format_duration).unwrap_or_default(),
+        format_duration(row.solve_time),
+        format_duration(row.total_time()),
+      ];
+
+      if with_allocations {
+        cells.extend(match row.allocations {
+          Some(allocations) => [
+            allocations.count.to_string(),
+            format_bytes(allocations.bytes),
+            format_bytes(allocations.peak),
+          ],
+          None => Default::default(),
+        });
+      }
+
+      cells
+    }).collect();
+  let total = rows.iter().map(Row::total_time).sum();
+
+  Cells {
+    header,
+    rows: cells,
+    total: format_duration(total),
+  }
+}
+
+/// Formats `rows` as a plain text table with a grand total at the end.
+pub fn table(rows: &[Row]) -> String {
+  let cells = cells(rows);
+  let mut widths: Vec<_> = cells.header.iter().map(|header| header.len()).collect();
+
+  for row in &cells.rows {
+    for (width, cell) in widths.iter_mut().zip(row) {
+      *width = (*width).max(cell.chars().count());
+    }
+  }
+  widths[5] = widths[5].max(cells.total.chars().count());
+
+  let mut output = String::new();
+  let mut write_row = |row: &[&str]| {
+    let line = row
+      .iter()
+      .zip(&widths)
+      .enumerate()
+      .map(|(i, (cell, width))| {
+        if is_left_aligned(i) {
+          format!("{:<width$}", cell, width = width)
+        } else {
+          format!("{:>width$}", cell, width = width)
+        }
+      }).collect::<Vec<_>>()
+      .join("  ");
+
+    writeln!(output, "{}", line.trim_end()).expect("Write error");
+  };
+
+  write_row(&cells.header);
+  for row in &cells.rows {
+    write_row(&row.iter().map(String::as_str).collect::<Vec<_>>());
+  }
+  write_row(&["", "", "", "", "", &cells.total]);
+
+  output
+}
+
+/// Formats `rows` as a Markdown table with a grand total at the end.
+pub fn markdown(rows: &[Row]) -> String {
+  let cells = cells(rows);
+  let alignments: Vec<_> = (0..cells.header.len())
+    .map(|i| if is_left_aligned(i) { ":---" } else { "---:" })
+    .collect();
+  let mut output = String::new();
+
+  writeln!(output, "| {} |", cells.header.join(" | ")).expect("Write error");
+  writeln!(output, "| {} |", alignments.join(" | ")).expect("Write error");
+  for row in &cells.rows {
+    writeln!(output, "| {} |", row.join(" | ")).expect("Write error");
+  }
+  writeln!(
+    output,
+    "| **Total** |{} **{}** |{}",
+    " |".repeat(4),
+    cells.total,
+    " |".repeat(cells.header.len() - HEADER.len())
+  ).expect("Write error");
+
+  output
+}
+
+/// How running a day and part went.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+  Ok,
+  Failed,
+  TimedOut,
+}
+
+impl fmt::Display for Status {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      Status::Ok => write!(f, "ok"),
+      Status::Failed => write!(f, "failed"),
+      Status::TimedOut => write!(f, "timed out"),
+    }
+  }
+}
+
+/// Parts of a day that gave no answer, because the input could not be read or
+/// parsed, or the solution panicked or timed out.
+#[derive(Debug)]
+pub struct Failure {
+  pub day: u32,
+  pub parts: Vec<Part>,
+  pub status: Status,
+  pub error: String,
+}
+
+/// Lists `failures` below the answers, once per day with the parts it cost.
+pub fn failures(failures: &[Failure]) -> String {
+  let mut output = String::new();
+
+  for failure in failures {
+    let parts: Vec<_> = failure.parts.iter().map(Part::to_string).collect();
+
+    writeln!(
+      output,
+      "Day {} {}: {}: {}",
+      failure.day,
+      parts.join(", "),
+      failure.status,
+      failure.error
+    ).expect("Write error");
+  }
+
+  output
+}
+
+/// The JSON record of one day and part, as printed by `--format json`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Record {
+  pub year: u32,
+  pub day: u32,
+  pub part: String,
+  pub status: Status,
+  pub answer: Option<String>,
+  /// The parse of the input the part was solved with, which the parts that
+  /// shared it each report.
+  pub parse_ns: Option<u64>,
+  pub solve_ns: Option<u64>,
+  pub error: Option<String>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub allocations: Option<Allocations>,
+}
+
+/// Formats `rows` and `failures` as JSON Lines, one object per day and part.
+pub fn json(year: u32, rows: &[Row], failures: &[Failure]) -> String {
+  let nanos = |duration: Duration| duration.as_nanos() as u64;
+  let parse_time = |day: u32| rows.iter().find(|row| row.day == day).and_then(|row| row.parse_time);
+  let solved = rows.iter().map(|row| Record {
+    year,
+    day: row.day,
+    part: row.part.to_string(),
+    status: Status::Ok,
+    answer: Some(row.answer.to_string()),
+    parse_ns: row.parse_time.or_else(|| parse_time(row.day)).map(nanos),
+    solve_ns: Some(nanos(row.solve_time)),
+    error: None,
+    allocations: row.allocations,
+  });
+  let failed = failures.iter().flat_map(|failure| {
+    failure.parts.iter().map(move |part| Record {
+      year,
+      day: failure.day,
+      part: part.to_string(),
+      status: failure.status,
+      answer: None,
+      parse_ns: None,
+      solve_ns: None,
+      error: Some(failure.error.clone()),
+      allocations: None,
+    })
+  });
+
+  let mut records: Vec<_> = solved.chain(failed).collect();
+  records.sort_by_key(|record| (record.day, record.part.clone()));
+
+  records
+    .iter()
+    .map(|record| serde_json::to_string(record).expect("Records serialize to JSON") + "\n")
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn rows() -> Vec<Row> {
+    Row::from_solved(
+      7,
+      Solved {
+        parse_time: Duration::from_micros(250),
+        answers: vec![
+          (Part::A, "CABDFE".into(), Duration::from_micros(1500)),
+          (Part::B, 15.into(), Duration::from_millis(2)),
+        ],
+        allocations: vec![None, None],
+      },
+    )
+  }
+
+  #[test]
+  fn test_format_duration() {
+    assert_eq!(format_duration(Duration::from_nanos(850)), "850 ns");
+    assert_eq!(format_duration(Duration::from_micros(1500)), "1.5 ms");
+    assert_eq!(format_duration(Duration::from_millis(2500)), "2.50 s");
+  }
+
+  #[test]
+  fn test_parse_time_is_counted_once() {
+    let rows = rows();
+
+    assert_eq!(rows[0].total_time(), Duration::from_micros(1750));
+    assert_eq!(rows[1].total_time(), Duration::from_millis(2));
+  }
+
+  #[test]
+  fn test_table() {
+    assert_eq!(
+      table(&rows()),
+      "Day  Part  Answer     Parse   Solve   Total
+  7  a     CABDFE  250.0 µs  1.5 ms  1.8 ms
+  7  b     15                2.0 ms  2.0 ms
+                                     3.8 ms
+"
+    );
+  }
+
+  #[test]
+  fn test_table_with_allocations() {
+    let mut rows = rows();
+    rows[1].allocations = Some(Allocations {
+      count: 12,
+      bytes: 3 << 20,
+      peak: 2048,
+    });
+
+    assert_eq!(
+      table(&rows),
+      "Day  Part  Answer     Parse   Solve   Total  Allocs  Allocated     Peak
+  7  a     CABDFE  250.0 µs  1.5 ms  1.8 ms
+  7  b     15                2.0 ms  2.0 ms      12    3.0 MiB  2.0 KiB
+                                     3.8 ms
+"
+    );
+  }
+
+  #[test]
+  fn test_markdown() {
+    assert_eq!(
+      markdown(&rows()),
+      "| Day | Part | Answer | Parse | Solve | Total |
+| ---: | :--- | :--- | ---: | ---: | ---: |
+| 7 | a | CABDFE | 250.0 µs | 1.5 ms | 1.8 ms |
+| 7 | b | 15 |  | 2.0 ms | 2.0 ms |
+| **Total** | | | | | **3.8 ms** |
+"
+    );
+  }
+
+  #[test]
+  fn test_json() {
+    let failures = [Failure {
+      day: 9,
+      parts: vec![Part::A],
+      status: Status::Failed,
+      error: "could not read day_09/a.input".to_string(),
+    }];
+    let json = json(2018, &rows(), &failures);
+    let lines: Vec<_> = json.lines().collect();
+
+    assert_eq!(
+      lines,
+      vec![
+        r#"{"year":2018,"day":7,"part":"a","status":"ok","answer":"CABDFE","parse_ns":250000,"solve_ns":1500000,"error":null}"#,
+        r#"{"year":2018,"day":7,"part":"b","status":"ok","answer":"15","parse_ns":250000,"solve_ns":2000000,"error":null}"#,
+        r#"{"year":2018,"day":9,"part":"a","status":"failed","answer":null,"parse_ns":null,"solve_ns":null,"error":"could not read day_09/a.input"}"#,
+      ]
+    );
+  }
+
+  #[test]
+  fn test_failures() {
+    let failures = [
+      Failure {
+        day: 1,
+        parts: vec![Part::B],
+        status: Status::TimedOut,
+        error: "no answer after 300.00 s".to_string(),
+      },
+      Failure {
+        day: 3,
+        parts: vec![Part::A, Part::B],
+        status: Status::Failed,
+        error: "day 3 input, line 2, column 1: expected a claim".to_string(),
+      },
+    ];
+
+    assert_eq!(
+      super::failures(&failures),
+      "Day 1 b: timed out: no answer after 300.00 s
+Day 3 a, b: failed: day 3 input, line 2, column 1: expected a claim
+"
+    );
+  }
+}
@@ -0,0 +1,339 @@
+use report::{format_duration, Status};
+use solution::{Part, Solve, Solved};
+use std::any::Any;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+/// How long each part of a day may run before it is given up on, unless
+/// `--timeout` says otherwise. The first part's time includes the parse.
+///
+/// Day 5 part b needs well over a minute in a debug build, so this is generous.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Why a part gave no answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Failed {
+  /// The input could not be parsed or the solution panicked.
+  Error(String),
+  TimedOut(Duration),
+}
+
+impl Failed {
+  pub fn status(&self) -> Status {
+    match self {
+      Failed::Error(_) => Status::Failed,
+      Failed::TimedOut(_) => Status::TimedOut,
+    }
+  }
+}
+
+impl fmt::Display for Failed {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      Failed::Error(error) => write!(f, "{}", error),
+      Failed::TimedOut(timeout) => write!(f, "no answer after {}", format_duration(*timeout)),
+    }
+  }
+}
+
+/// How running the parts of a day went.
+#[derive(Debug)]
+pub struct Outcome {
+  /// The parts that were solved, if the input could be parsed.
+  pub solved: Option<Solved>,
+  pub failed: Vec<(Part, Failed)>,
+}
+
+/// What the thread of a day sends while it runs.
+enum Message {
+  /// What has been solved so far, once the input is parsed and after each part.
+  Progress(Solved),
+  Failed(Failed),
+  Done(Solved),
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+  let message = payload
+    .downcast_ref::<&str>()
+    .map(|message| message.to_string())
+    .or_else(|| payload.downcast_ref::<String>().cloned())
+    .unwrap_or_else(|| "no message".to_string());
+
+  format!("panicked: {}", message)
+}
+
+/// Solves `parts` with one parse of `input` on a thread of its own, catching
+/// panics and giving up on a part after `timeout`.
+///
+/// Gives back what was solved before a part failed, and why it failed.
+fn attempt(day: u32, solve: Solve, input: Arc<str>, parts: Vec<Part>, timeout: Option<Duration>) -> (Option<Solved>, Option<Failed>) {
+  let (sender, receiver) = mpsc::channel();
+
+  let spawned = thread::Builder::new()
+    .name(format!("day {}", day))
+    .spawn(move || {
+      let _span = info_span!("day", day).entered();
+      let progress = sender.clone();
+      let mut progress = |solved: &Solved| {
+        let _ = progress.send(Message::Progress(solved.clone()));
+      };
+      let message = match panic::catch_unwind(AssertUnwindSafe(|| solve(&input, &parts, &mut progress))) {
+        Ok(Ok(solved)) => Message::Done(solved),
+        Ok(Err(error)) => Message::Failed(Failed::Error(error.to_string())),
+        Err(payload) => Message::Failed(Failed::Error(panic_message(payload))),
+      };
+
+      // Nobody is listening any more if the run timed out.
+      let _ = sender.send(message);
+    });
+
+  if let Err(error) = spawned {
+    return (None, Some(Failed::Error(format!("could not start a thread: {}", error))));
+  }
+
+  let mut solved = None;
+
+  loop {
+    let message = match timeout {
+      Some(timeout) => receiver.recv_timeout(timeout),
+      None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+    };
+
+    match message {
+      Ok(Message::Progress(progress)) => solved = Some(progress),
+      Ok(Message::Failed(failed)) => return (solved, Some(failed)),
+      Ok(Message::Done(done)) => return (Some(done), None),
+      Err(RecvTimeoutError::Timeout) => {
+        let timeout = timeout.expect("Only a run with a timeout times out");
+        info!(day, "gave up after {:?}, the solution keeps running in the background", timeout);
+        return (solved, Some(Failed::TimedOut(timeout)));
+      }
+      Err(RecvTimeoutError::Disconnected) => {
+        return (solved, Some(Failed::Error("the solution thread died".to_string())))
+      }
+    }
+  }
+}
+
+/// Solves `parts` of `day` isolated from the rest of the run, catching panics
+/// and giving up on a part after `timeout`.
+///
+/// The input is parsed once, unless a part panics or times out: the parts
+/// after it then get a fresh thread and parse, so they are still answered. A
+/// part that times out can't be stopped, so its thread is left running in the
+/// background until the process exits.
+pub fn run_isolated(day: u32, solve: Solve, input: Arc<str>, parts: Vec<Part>, timeout: Option<Duration>) -> Outcome {
+  let mut outcome = Outcome {
+    solved: None,
+    failed: vec![],
+  };
+  let mut parts = &parts[..];
+
+  while !parts.is_empty() {
+    let (solved, failed) = attempt(day, solve, input.clone(), parts.to_vec(), timeout);
+    let parsed = solved.is_some();
+    let answered = solved.as_ref().map_or(0, |solved| solved.answers.len());
+
+    match (&mut outcome.solved, solved) {
+      (Some(all), Some(solved)) => {
+        all.answers.extend(solved.answers);
+        all.allocations.extend(solved.allocations);
+      }
+      (all, solved) => *all = all.take().or(solved),
+    }
+
+    match failed {
+      None => break,
+      // The part after the answered ones failed.
+      Some(failed) if parsed => {
+        outcome.failed.push((parts[answered], failed));
+        parts = &parts[answered + 1..];
+      }
+      // Without a parse, every part fails the same way.
+      Some(failed) => {
+        outcome.failed.extend(parts.iter().map(|&part| (part, failed.clone())));
+        break;
+      }
+    }
+  }
+
+  outcome
+}
+
+/// The parts of a day to solve, which share one parse of its input.
+#[derive(Clone)]
+pub struct Job {
+  pub day: u32,
+  pub parts: Vec<Part>,
+  pub solve: Solve,
+  pub input: Arc<str>,
+}
+
+/// Runs `jobs` on `workers` threads, handing back the outcomes in the order of `jobs`.
+///
+/// Timings are taken inside each solution, so time spent waiting for a free
+/// worker doesn't count.
+pub fn run_jobs(jobs: Vec<Job>, workers: usize, timeout: Option<Duration>) -> Vec<Outcome> {
+  let queue = Mutex::new(jobs.into_iter().enumerate());
+  let (sender, receiver) = mpsc::channel();
+
+  thread::scope(|scope| {
+    for _ in 0..workers.max(1) {
+      let sender = sender.clone();
+      let queue = &queue;
+
+      scope.spawn(move || loop {
+        let next = queue.lock().expect("Jobs are taken without panicking").next();
+        let (i, job) = match next {
+          Some(next) => next,
+          None => break,
+        };
+        let outcome = run_isolated(job.day, job.solve, job.input, job.parts, timeout);
+
+        sender.send((i, outcome)).expect("Outcomes are received after all workers finish");
+      });
+    }
+  });
+  drop(sender);
+
+  let mut outcomes: Vec<_> = receiver.into_iter().collect();
+  outcomes.sort_by_key(|&(i, _)| i);
+
+  outcomes.into_iter().map(|(_, outcome)| outcome).collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use error::ParseError;
+  use solution::Answer;
+
+  fn answers(_: &str, parts: &[Part], _: &mut dyn FnMut(&Solved)) -> Result<Solved, ParseError> {
+    Ok(Solved {
+      parse_time: Duration::from_millis(1),
+      answers: parts.iter().map(|&part| (part, Answer::from(7), Duration::from_millis(1))).collect(),
+      allocations: parts.iter().map(|_| None).collect(),
+    })
+  }
+
+  fn panics(input: &str, _: &[Part], _: &mut dyn FnMut(&Solved)) -> Result<Solved, ParseError> {
+    panic!("unexpected input {:?}", input)
+  }
+
+  fn hangs(_: &str, _: &[Part], _: &mut dyn FnMut(&Solved)) -> Result<Solved, ParseError> {
+    loop {
+      thread::sleep(Duration::from_millis(10));
+    }
+  }
+
+  fn sleeps(input: &str, _: &[Part], _: &mut dyn FnMut(&Solved)) -> Result<Solved, ParseError> {
+    thread::sleep(Duration::from_millis(input.parse().unwrap()));
+
+    Ok(Solved {
+      parse_time: Duration::from_millis(0),
+      answers: vec![(Part::A, Answer::from(input), Duration::from_millis(0))],
+      allocations: vec![None],
+    })
+  }
+
+  /// Solves each part like `solution::solve_each`, except for the part that `input` fails.
+  fn fails_part(input: &str, parts: &[Part], progress: &mut dyn FnMut(&Solved)) -> Result<Solved, ParseError> {
+    let mut solved = Solved {
+      parse_time: Duration::from_millis(1),
+      answers: vec![],
+      allocations: vec![],
+    };
+    progress(&solved);
+
+    for &part in parts {
+      match (input, part) {
+        ("a panics", Part::A) => panic!("part a panicked"),
+        ("b panics", Part::B) => panic!("part b panicked"),
+        ("a hangs", Part::A) => loop {
+          thread::sleep(Duration::from_millis(10));
+        },
+        _ => (),
+      }
+
+      solved.answers.push((part, Answer::from(7), Duration::from_millis(1)));
+      solved.allocations.push(None);
+      progress(&solved);
+    }
+
+    Ok(solved)
+  }
+
+  fn solved_parts(outcome: &Outcome) -> Vec<Part> {
+    outcome
+      .solved
+      .iter()
+      .flat_map(|solved| solved.answers.iter().map(|&(part, _, _)| part))
+      .collect()
+  }
+
+  #[test]
+  fn test_solved() {
+    let outcome = run_isolated(1, answers, "+1".into(), vec![Part::A], None);
+
+    assert_eq!(outcome.solved.unwrap().answers[0].1, Answer::from(7));
+    assert_eq!(outcome.failed, vec![]);
+  }
+
+  #[test]
+  fn test_panic_is_caught() {
+    let outcome = run_isolated(1, panics, "x".into(), vec![Part::A, Part::B], None);
+    let failed = Failed::Error("panicked: unexpected input \"x\"".to_string());
+
+    assert!(outcome.solved.is_none());
+    assert_eq!(outcome.failed, vec![(Part::A, failed.clone()), (Part::B, failed)]);
+  }
+
+  #[test]
+  fn test_timeout() {
+    let outcome = run_isolated(1, hangs, "".into(), vec![Part::B], Some(Duration::from_millis(50)));
+
+    assert_eq!(outcome.failed, vec![(Part::B, Failed::TimedOut(Duration::from_millis(50)))]);
+    assert_eq!(outcome.failed[0].1.to_string(), "no answer after 50.0 ms");
+  }
+
+  #[test]
+  fn test_failed_part_spares_the_others() {
+    let run = |input: &str| {
+      run_isolated(1, fails_part, input.into(), vec![Part::A, Part::B], Some(Duration::from_millis(50)))
+    };
+
+    let outcome = run("b panics");
+    assert_eq!(solved_parts(&outcome), vec![Part::A]);
+    assert_eq!(outcome.failed, vec![(Part::B, Failed::Error("panicked: part b panicked".to_string()))]);
+
+    let outcome = run("a panics");
+    assert_eq!(solved_parts(&outcome), vec![Part::B]);
+    assert_eq!(outcome.failed, vec![(Part::A, Failed::Error("panicked: part a panicked".to_string()))]);
+
+    let outcome = run("a hangs");
+    assert_eq!(solved_parts(&outcome), vec![Part::B]);
+    assert_eq!(outcome.failed, vec![(Part::A, Failed::TimedOut(Duration::from_millis(50)))]);
+  }
+
+  #[test]
+  fn test_run_jobs_in_order() {
+    let job = |day, input: &str| Job {
+      day,
+      parts: vec![Part::A],
+      solve: sleeps,
+      input: input.into(),
+    };
+    let jobs = vec![job(5, "60"), job(7, "0"), job(9, "30"), job(1, "x")];
+
+    let answers: Vec<_> = run_jobs(jobs, 3, None)
+      .into_iter()
+      .map(|outcome| outcome.solved.map(|solved| solved.answers[0].1.to_string()))
+      .collect();
+
+    assert_eq!(answers, vec![Some("60".into()), Some("0".into()), Some("30".into()), None]);
+  }
+}
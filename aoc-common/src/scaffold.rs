@@ -0,0 +1,135 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+const TEMPLATES: [(&str, &str); 4] = [
+  ("mod.rs", include_str!("../templates/mod.rs.tmpl")),
+  ("a.rs", include_str!("../templates/a.rs.tmpl")),
+  ("b.rs", include_str!("../templates/b.rs.tmpl")),
+  ("a.input", ""),
+];
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+  AlreadyExists(PathBuf),
+  Io(PathBuf, io::Error),
+}
+
+impl fmt::Display for ScaffoldError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      ScaffoldError::AlreadyExists(path) => write!(f, "{} already exists", path.display()),
+      ScaffoldError::Io(path, error) => write!(f, "could not write {}: {}", path.display(), error),
+    }
+  }
+}
+
+fn render(template: &str, day: u32) -> String {
+  template
+    .replace("{{day}}", &format!("{:02}", day))
+    .replace("{{number}}", &day.to_string())
+}
+
+/// Adds `pub mod day_NN;` to the day modules declared in `lib`, keeping them sorted.
+fn register(lib: &str, day: u32) -> String {
+  let declaration = format!("pub mod day_{:02};", day);
+  let mut lines: Vec<&str> = lib.lines().collect();
+  let days: Vec<usize> = lines
+    .iter()
+    .enumerate()
+    .filter(|(_, line)| line.starts_with("pub mod day_"))
+    .map(|(i, _)| i)
+    .collect();
+
+  let position = days
+    .iter()
+    .find(|&&i| lines[i] > declaration.as_str())
+    .cloned()
+    .or_else(|| days.last().map(|i| i + 1))
+    .unwrap_or(0);
+
+  lines.insert(position, &declaration);
+
+  lines.join("\n") + "\n"
+}
+
+/// Creates `day_NN/` in `src` from the templates and declares it in `lib.rs`.
+pub fn scaffold(src: &Path, day: u32) -> Result<Vec<PathBuf>, ScaffoldError> {
+  let dir = src.join(format!("day_{:02}", day));
+
+  if dir.exists() {
+    return Err(ScaffoldError::AlreadyExists(dir));
+  }
+
+  fs::create_dir(&dir).map_err(|error| ScaffoldError::Io(dir.clone(), error))?;
+
+  let mut created = vec![];
+
+  for (name, template) in &TEMPLATES {
+    let path = dir.join(name);
+
+    fs::write(&path, render(template, day)).map_err(|error| ScaffoldError::Io(path.clone(), error))?;
+    created.push(path);
+  }
+
+  let lib = src.join("lib.rs");
+  let contents = fs::read_to_string(&lib).map_err(|error| ScaffoldError::Io(lib.clone(), error))?;
+
+  fs::write(&lib, register(&contents, day)).map_err(|error| ScaffoldError::Io(lib.clone(), error))?;
+  created.push(lib);
+
+  Ok(created)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::env;
+
+  #[test]
+  fn test_register() {
+    let lib = "pub mod solution;
+
+pub mod day_01;
+pub mod day_09;
+
+pub fn solve() {}
+";
+
+    assert_eq!(
+      register(lib, 7),
+      "pub mod solution;
+
+pub mod day_01;
+pub mod day_07;
+pub mod day_09;
+
+pub fn solve() {}
+"
+    );
+    assert!(register(lib, 10).contains("pub mod day_09;\npub mod day_10;\n\npub fn solve"));
+  }
+
+  #[test]
+  fn test_render() {
+    assert!(render(TEMPLATES[0].1, 10).contains("#[aoc(day = 10, part = \"a\")]\nimpl Solution for Day10 {"));
+    assert!(!render(TEMPLATES[2].1, 10).contains("unimplemented!"));
+  }
+
+  #[test]
+  fn test_scaffold_refuses_existing_day() {
+    let src = env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+    fs::create_dir_all(&src).unwrap();
+    fs::write(src.join("lib.rs"), "pub mod day_01;\n").unwrap();
+
+    let created = scaffold(&src, 10).unwrap();
+    let error = scaffold(&src, 10).unwrap_err();
+    let lib = fs::read_to_string(src.join("lib.rs")).unwrap();
+    fs::remove_dir_all(&src).unwrap();
+
+    assert_eq!(created.len(), 5);
+    assert_eq!(lib, "pub mod day_01;\npub mod day_10;\n");
+    assert_eq!(error.to_string(), format!("{} already exists", src.join("day_10").display()));
+  }
+}
@@ -0,0 +1,207 @@
+use error::ParseError;
+use std::num::IntErrorKind;
+
+/// Where and why a line did not match a pattern.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScanError {
+  /// Where in the line it went wrong, in bytes.
+  pub offset: usize,
+  pub message: String,
+}
+
+impl ScanError {
+  /// The error as a `ParseError` of `day`, for line `line` of the input, which is `row`.
+  pub fn at(self, day: u32, line: usize, row: &str) -> ParseError {
+    ParseError::new(day, line, row, self.offset, self.message)
+  }
+}
+
+/// A type that a field of a pattern can be parsed as.
+pub trait Field<'a>: Sized {
+  /// Parses the text of a field, or says what was expected instead.
+  fn parse(text: &'a str) -> Result<Self, String>;
+
+  /// Parses the text of a field that starts at `offset` in the line.
+  fn parse_at(_offset: usize, text: &'a str) -> Result<Self, String> {
+    Self::parse(text)
+  }
+}
+
+/// A field together with where it starts in the line, in bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Spanned<T> {
+  pub offset: usize,
+  pub value: T,
+}
+
+impl<'a, T: Field<'a>> Field<'a> for Spanned<T> {
+  fn parse(text: &'a str) -> Result<Self, String> {
+    Self::parse_at(0, text)
+  }
+
+  fn parse_at(offset: usize, text: &'a str) -> Result<Self, String> {
+    T::parse_at(offset, text).map(|value| Spanned { offset, value })
+  }
+}
+
+impl<'a> Field<'a> for &'a str {
+  fn parse(text: &'a str) -> Result<Self, String> {
+    Ok(text)
+  }
+}
+
+impl<'a> Field<'a> for String {
+  fn parse(text: &'a str) -> Result<Self, String> {
+    Ok(text.to_string())
+  }
+}
+
+impl<'a> Field<'a> for char {
+  fn parse(text: &'a str) -> Result<Self, String> {
+    let mut chars = text.chars();
+
+    match (chars.next(), chars.next()) {
+      (Some(c), None) => Ok(c),
+      _ => Err("expected a single character".to_string()),
+    }
+  }
+}
+
+macro_rules! integer_fields {
+  ($($ty:ty)*) => {
+    $(
+      impl<'a> Field<'a> for $ty {
+        fn parse(text: &'a str) -> Result<Self, String> {
+          text.parse().map_err(|error: std::num::ParseIntError| {
+            match error.kind() {
+              IntErrorKind::PosOverflow => "number is too large",
+              IntErrorKind::NegOverflow => "number is too small",
+              _ => "expected a number",
+            }.to_string()
+          })
+        }
+      }
+    )*
+  };
+}
+
+integer_fields!(u8 u16 u32 u64 usize i8 i16 i32 i64 isize);
+
+/// How many bytes at the start of `text` match `literal`.
+fn matched(text: &str, literal: &str) -> usize {
+  text
+    .chars()
+    .zip(literal.chars())
+    .take_while(|(a, b)| a == b)
+    .map(|(c, _)| c.len_utf8())
+    .sum()
+}
+
+/// Where `literal` stops matching `rest`, which starts with a field that is
+/// taken to be a word or a signed number.
+fn mismatch(rest: &str, literal: &str) -> usize {
+  let end = rest
+    .char_indices()
+    .find(|&(i, c)| !(c.is_alphanumeric() || i == 0 && (c == '+' || c == '-')))
+    .map_or(rest.len(), |(i, _)| i);
+
+  end + matched(&rest[end..], literal)
+}
+
+/// Splits `input` around `literals`, the text before, between and after the
+/// fields of a `scan!` pattern, into the fields and where they start.
+///
+/// Each field ends at the first occurrence of the text after it, except the
+/// last, which runs up to the text ending the pattern. A line that does not
+/// match is reported where the missing text should be, with `pattern`
+/// describing the whole pattern.
+pub fn fields<'a>(literals: &[&str], pattern: &str, input: &'a str) -> Result<Vec<(usize, &'a str)>, ScanError> {
+  let missing = |offset: usize, literal: &str| ScanError {
+    offset,
+    message: format!("expected \"{}\" in \"{}\"", literal, pattern),
+  };
+
+  let (first, literals) = literals.split_first().expect("a pattern starts with a literal");
+  let mut rest = input.strip_prefix(first).ok_or_else(|| missing(matched(input, first), first))?;
+  let mut offset = first.len();
+  let mut fields = vec![];
+
+  for (i, literal) in literals.iter().enumerate() {
+    let end = if i + 1 < literals.len() {
+      rest.find(literal)
+    } else {
+      rest.strip_suffix(literal).map(str::len)
+    };
+    let end = end.ok_or_else(|| missing(offset + mismatch(rest, literal), literal))?;
+
+    if end == 0 {
+      return Err(ScanError {
+        offset,
+        message: "expected a value".to_string(),
+      });
+    }
+
+    fields.push((offset, &rest[..end]));
+    rest = &rest[end + literal.len()..];
+    offset += end + literal.len();
+  }
+
+  Ok(fields)
+}
+
+/// Parses a field found by `fields`.
+pub fn field<'a, T: Field<'a>>((offset, text): (usize, &'a str)) -> Result<T, ScanError> {
+  T::parse_at(offset, text).map_err(|message| ScanError { offset, message })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const CLAIM: [&str; 6] = ["#", " @ ", ",", ": ", "x", ""];
+  const CLAIM_PATTERN: &str = "#<id> @ <left>,<top>: <width>x<height>";
+
+  #[test]
+  fn test_fields() {
+    assert_eq!(
+      fields(&CLAIM, CLAIM_PATTERN, "#123 @ 3,2: 5x4"),
+      Ok(vec![(1, "123"), (7, "3"), (9, "2"), (12, "5"), (14, "4")])
+    );
+    assert_eq!(
+      fields(&["[", "] ", ""], "", "[1518-11-01 00:00] wakes up] "),
+      Ok(vec![(1, "1518-11-01 00:00"), (19, "wakes up] ")])
+    );
+  }
+
+  #[test]
+  fn test_fields_mismatch() {
+    let error = fields(&CLAIM, CLAIM_PATTERN, "#2 @ 3,1 4x4").unwrap_err();
+
+    assert_eq!(error.offset, 8);
+    assert_eq!(error.message, "expected \": \" in \"#<id> @ <left>,<top>: <width>x<height>\"");
+    assert_eq!(fields(&CLAIM, CLAIM_PATTERN, "#2 @ 3,1:4x4").unwrap_err().offset, 9);
+    assert_eq!(fields(&CLAIM, CLAIM_PATTERN, "#2 @ 3;1: 4x4").unwrap_err().offset, 6);
+    assert_eq!(fields(&CLAIM, CLAIM_PATTERN, "2 @ 3,1: 4x4").unwrap_err().offset, 0);
+    assert_eq!(fields(&CLAIM, CLAIM_PATTERN, "#2 @ 3,1: 4x").unwrap_err().offset, 12);
+    assert_eq!(fields(&CLAIM, CLAIM_PATTERN, "#2 @ ,1: 4x4").unwrap_err().offset, 5);
+    assert_eq!(fields(&["", " points"], "<points> points", "25 point").unwrap_err().offset, 8);
+  }
+
+  #[test]
+  fn test_field() {
+    assert_eq!(field::<u32>((3, "42")), Ok(42));
+    assert_eq!(field::<i32>((0, "+7")), Ok(7));
+    assert_eq!(field::<char>((5, "C")), Ok('C'));
+    assert_eq!(field::<&str>((0, "wakes up")), Ok("wakes up"));
+    assert_eq!(
+      field::<u8>((2, "300")).unwrap_err(),
+      ScanError {
+        offset: 2,
+        message: "number is too large".to_string(),
+      }
+    );
+    assert_eq!(field::<u32>((2, "x")).unwrap_err().message, "expected a number");
+    assert_eq!(field::<char>((2, "AB")).unwrap_err().message, "expected a single character");
+    assert_eq!(field::<Spanned<u32>>((7, "3")), Ok(Spanned { offset: 7, value: 3 }));
+  }
+}
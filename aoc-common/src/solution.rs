@@ -0,0 +1,174 @@
+use memory::{self, Allocations};
+use error::ParseError;
+use text;
+use std::fmt;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+  Signed(i64),
+  Unsigned(u64),
+  Text(String),
+}
+
+impl fmt::Display for Answer {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      Answer::Signed(value) => write!(f, "{}", value),
+      Answer::Unsigned(value) => write!(f, "{}", value),
+      Answer::Text(value) => write!(f, "{}", value),
+    }
+  }
+}
+
+impl From<i32> for Answer {
+  fn from(value: i32) -> Self {
+    Answer::Signed(value.into())
+  }
+}
+
+impl From<i64> for Answer {
+  fn from(value: i64) -> Self {
+    Answer::Signed(value)
+  }
+}
+
+impl From<u32> for Answer {
+  fn from(value: u32) -> Self {
+    Answer::Unsigned(value.into())
+  }
+}
+
+impl From<u64> for Answer {
+  fn from(value: u64) -> Self {
+    Answer::Unsigned(value)
+  }
+}
+
+impl From<usize> for Answer {
+  fn from(value: usize) -> Self {
+    Answer::Unsigned(value as u64)
+  }
+}
+
+impl From<String> for Answer {
+  fn from(value: String) -> Self {
+    Answer::Text(value)
+  }
+}
+
+impl<'a> From<&'a str> for Answer {
+  fn from(value: &'a str) -> Self {
+    Answer::Text(value.to_string())
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+  A,
+  B,
+}
+
+impl fmt::Display for Part {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      Part::A => write!(f, "a"),
+      Part::B => write!(f, "b"),
+    }
+  }
+}
+
+impl FromStr for Part {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s {
+      "a" => Ok(Part::A),
+      "b" => Ok(Part::B),
+      _ => Err(format!("part must be \"a\" or \"b\", got \"{}\"", s)),
+    }
+  }
+}
+
+/// A day's puzzle, split into parsing the input and solving each part.
+///
+/// The parsed input is shared between both parts, so `part_a` and `part_b`
+/// only borrow it.
+pub trait Solution {
+  type Input<'a>;
+
+  fn parse(input: &str) -> Result<Self::Input<'_>, ParseError>;
+  fn part_a(input: &Self::Input<'_>) -> Answer;
+  fn part_b(input: &Self::Input<'_>) -> Answer;
+}
+
+/// The answers of a run together with how long each step took.
+#[derive(Debug, Clone)]
+pub struct Solved {
+  pub parse_time: Duration,
+  pub answers: Vec<(Part, Answer, Duration)>,
+  /// What solving each part allocated, when allocations are counted.
+  pub allocations: Vec<Option<Allocations>>,
+}
+
+/// `solve_each` for a particular solution, so days can be listed side by side.
+pub type Solve = fn(&str, &[Part], &mut dyn FnMut(&Solved)) -> Result<Solved, ParseError>;
+
+/// Parses the normalized `input` once and solves each of `parts` of the solution `S` with it.
+pub fn solve<S: Solution>(input: &str, parts: &[Part]) -> Result<Solved, ParseError> {
+  solve_each::<S>(input, parts, &mut |_| ())
+}
+
+/// `solve`, handing `progress` what has been solved so far once the input is
+/// parsed and after each part.
+pub fn solve_each<S: Solution>(
+  input: &str,
+  parts: &[Part],
+  progress: &mut dyn FnMut(&Solved),
+) -> Result<Solved, ParseError> {
+  let start = Instant::now();
+  let input = text::normalize(input);
+  let input = info_span!("parse").in_scope(|| S::parse(&input))?;
+
+  let mut solved = Solved {
+    parse_time: start.elapsed(),
+    answers: vec![],
+    allocations: vec![],
+  };
+  progress(&solved);
+
+  for &part in parts {
+    let _span = info_span!("solve", %part).entered();
+    let start = Instant::now();
+    let (answer, allocated) = memory::measure(|| match part {
+      Part::A => S::part_a(&input),
+      Part::B => S::part_b(&input),
+    });
+
+    solved.answers.push((part, answer, start.elapsed()));
+    solved.allocations.push(allocated);
+    progress(&solved);
+  }
+
+  Ok(solved)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_answer_display() {
+    assert_eq!(Answer::from(-12).to_string(), "-12");
+    assert_eq!(Answer::from(3352507536u64).to_string(), "3352507536");
+    assert_eq!(Answer::from("CABDFE").to_string(), "CABDFE");
+  }
+
+  #[test]
+  fn test_parse_part() {
+    assert_eq!("a".parse(), Ok(Part::A));
+    assert_eq!("b".parse(), Ok(Part::B));
+    assert!("c".parse::<Part>().is_err());
+  }
+}
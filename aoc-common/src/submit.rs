@@ -0,0 +1,219 @@
+use answers::{Answers, AnswersError, Check};
+use client::{Client, ClientError, Submission, Verdict};
+use serde_json;
+use solution::Part;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// An answer submitted to the puzzle site and what it said about it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Guess {
+  pub year: u32,
+  pub day: u32,
+  pub part: String,
+  pub answer: String,
+  pub verdict: Verdict,
+}
+
+/// Why an answer is not worth submitting.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+  AlreadyCorrect,
+  KnownAnswer(String),
+  AlreadyGuessed(Verdict),
+  NotBelow(String),
+  NotAbove(String),
+}
+
+impl fmt::Display for Refusal {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      Refusal::AlreadyCorrect => write!(f, "the answer is already recorded as correct"),
+      Refusal::KnownAnswer(answer) => write!(f, "the recorded answer is {}", answer),
+      Refusal::AlreadyGuessed(verdict) => write!(f, "the answer was already guessed and was {}", verdict),
+      Refusal::NotBelow(bound) => write!(f, "{} was already too high", bound),
+      Refusal::NotAbove(bound) => write!(f, "{} was already too low", bound),
+    }
+  }
+}
+
+#[derive(Debug)]
+pub enum SubmitError {
+  Refused(Refusal),
+  Client(ClientError),
+  Answers(AnswersError),
+  Io(PathBuf, io::Error),
+  Json(PathBuf, serde_json::Error),
+}
+
+impl fmt::Display for SubmitError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      SubmitError::Refused(refusal) => write!(f, "not submitting, {}", refusal),
+      SubmitError::Client(error) => write!(f, "{}", error),
+      SubmitError::Answers(error) => write!(f, "{}", error),
+      SubmitError::Io(path, error) => write!(f, "could not access {}: {}", path.display(), error),
+      SubmitError::Json(path, error) => write!(f, "could not parse {}: {}", path.display(), error),
+    }
+  }
+}
+
+/// Every answer submitted so far, oldest first.
+#[derive(Debug)]
+pub struct Guesses {
+  path: PathBuf,
+  guesses: Vec<Guess>,
+}
+
+impl Guesses {
+  /// Loads the guesses at `path`, which may not exist yet.
+  pub fn load(path: &Path) -> Result<Guesses, SubmitError> {
+    let guesses = match fs::read_to_string(path) {
+      Ok(contents) => {
+        serde_json::from_str(&contents).map_err(|error| SubmitError::Json(path.to_path_buf(), error))?
+      }
+      Err(ref error) if error.kind() == io::ErrorKind::NotFound => vec![],
+      Err(error) => return Err(SubmitError::Io(path.to_path_buf(), error)),
+    };
+
+    Ok(Guesses {
+      path: path.to_path_buf(),
+      guesses,
+    })
+  }
+
+  pub fn get(&self, year: u32, day: u32, part: Part) -> impl Iterator<Item = &Guess> {
+    let part = part.to_string();
+
+    self
+      .guesses
+      .iter()
+      .filter(move |guess| (guess.year, guess.day, &guess.part) == (year, day, &part))
+  }
+
+  pub fn push(&mut self, guess: Guess) {
+    self.guesses.push(guess);
+  }
+
+  /// Refuses answers that were guessed before, or that earlier guesses show to be too high or low.
+  pub fn check(&self, year: u32, day: u32, part: Part, answer: &str) -> Result<(), Refusal> {
+    if let Some(guess) = self.get(year, day, part).find(|guess| guess.answer == answer) {
+      return Err(Refusal::AlreadyGuessed(guess.verdict));
+    }
+
+    let number = match answer.parse::<i64>() {
+      Ok(number) => number,
+      Err(_) => return Ok(()),
+    };
+    let bound = |verdict| {
+      self
+        .get(year, day, part)
+        .filter(move |guess| guess.verdict == verdict)
+        .filter_map(|guess| guess.answer.parse::<i64>().ok())
+    };
+
+    if let Some(too_high) = bound(Verdict::TooHigh).min().filter(|&too_high| number >= too_high) {
+      return Err(Refusal::NotBelow(too_high.to_string()));
+    }
+
+    if let Some(too_low) = bound(Verdict::TooLow).max().filter(|&too_low| number <= too_low) {
+      return Err(Refusal::NotAbove(too_low.to_string()));
+    }
+
+    Ok(())
+  }
+
+  pub fn save(&self) -> Result<(), SubmitError> {
+    let contents = serde_json::to_string_pretty(&self.guesses).expect("Guesses serialize to JSON");
+
+    fs::write(&self.path, contents + "\n").map_err(|error| SubmitError::Io(self.path.clone(), error))
+  }
+}
+
+/// Submits `answer` unless it is known to be wrong, recording the guess and,
+/// when it is correct, the answer.
+pub fn submit(
+  client: &Client,
+  answers: &mut Answers,
+  guesses: &mut Guesses,
+  year: u32,
+  day: u32,
+  part: Part,
+  answer: &str,
+) -> Result<Submission, SubmitError> {
+  match answers.check(year, day, part, answer) {
+    Check::Correct => return Err(SubmitError::Refused(Refusal::AlreadyCorrect)),
+    Check::Wrong { expected } => return Err(SubmitError::Refused(Refusal::KnownAnswer(expected.to_string()))),
+    Check::Unrecorded => {}
+  }
+
+  guesses
+    .check(year, day, part, answer)
+    .map_err(SubmitError::Refused)?;
+
+  let submission = client
+    .submit(year, day, part, answer)
+    .map_err(SubmitError::Client)?;
+
+  if let Submission::Judged(verdict) = submission {
+    guesses.push(Guess {
+      year,
+      day,
+      part: part.to_string(),
+      answer: answer.to_string(),
+      verdict,
+    });
+    guesses.save()?;
+
+    if verdict == Verdict::Correct {
+      answers.insert(year, day, part, answer.to_string());
+      answers.save().map_err(SubmitError::Answers)?;
+    }
+  }
+
+  Ok(submission)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn guesses(guesses: &[(&str, Verdict)]) -> Guesses {
+    Guesses {
+      path: PathBuf::from("guesses.json"),
+      guesses: guesses
+        .iter()
+        .map(|&(answer, verdict)| Guess {
+          year: 2018,
+          day: 10,
+          part: "a".to_string(),
+          answer: answer.to_string(),
+          verdict,
+        }).collect(),
+    }
+  }
+
+  #[test]
+  fn test_check_bounds() {
+    let guesses = guesses(&[("10", Verdict::TooLow), ("50", Verdict::TooHigh), ("40", Verdict::TooHigh)]);
+
+    assert_eq!(guesses.check(2018, 10, Part::A, "39"), Ok(()));
+    assert_eq!(guesses.check(2018, 10, Part::A, "45"), Err(Refusal::NotBelow("40".to_string())));
+    assert_eq!(guesses.check(2018, 10, Part::A, "10"), Err(Refusal::AlreadyGuessed(Verdict::TooLow)));
+    assert_eq!(guesses.check(2018, 10, Part::A, "7"), Err(Refusal::NotAbove("10".to_string())));
+    assert_eq!(guesses.check(2018, 10, Part::B, "45"), Ok(()));
+  }
+
+  #[test]
+  fn test_check_text_answers() {
+    let guesses = guesses(&[("CABFDE", Verdict::Wrong)]);
+
+    assert_eq!(guesses.check(2018, 10, Part::A, "CABDFE"), Ok(()));
+    assert_eq!(
+      guesses.check(2018, 10, Part::A, "CABFDE"),
+      Err(Refusal::AlreadyGuessed(Verdict::Wrong))
+    );
+  }
+}
@@ -0,0 +1,106 @@
+use std::borrow::Cow;
+use std::iter;
+
+const BOM: char = '\u{feff}';
+
+/// `input` without a byte order mark, with `\n` line endings and without
+/// trailing whitespace on any line, which is what every day's `parse` is given.
+pub fn normalize(input: &str) -> Cow<'_, str> {
+  let input = input.strip_prefix(BOM).unwrap_or(input).trim_end();
+
+  if input.contains('\r') || input.lines().any(|line| line.ends_with(char::is_whitespace)) {
+    Cow::Owned(input.lines().map(str::trim_end).collect::<Vec<_>>().join("\n"))
+  } else {
+    Cow::Borrowed(input)
+  }
+}
+
+/// The non-empty lines of `input`, numbered from 1.
+pub fn lines(input: &str) -> impl Iterator<Item = (usize, &str)> {
+  input
+    .lines()
+    .enumerate()
+    .map(|(i, line)| (i + 1, line))
+    .filter(|(_, line)| !line.is_empty())
+}
+
+/// A whitespace-separated token of the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Token<'a> {
+  pub line: usize,
+  /// The line the token is on.
+  pub row: &'a str,
+  /// Where the token starts in `row`, in bytes.
+  pub offset: usize,
+  pub text: &'a str,
+}
+
+/// The whitespace-separated tokens of `input`, with where each of them is.
+pub fn tokens(input: &str) -> impl Iterator<Item = Token<'_>> {
+  lines(input).flat_map(|(line, row)| {
+    let mut end = 0;
+
+    iter::from_fn(move || {
+      let offset = end + row[end..].find(|c: char| !c.is_whitespace())?;
+      end = row[offset..].find(char::is_whitespace).map_or(row.len(), |len| offset + len);
+
+      Some(Token {
+        line,
+        row,
+        offset,
+        text: &row[offset..end],
+      })
+    })
+  })
+}
+
+/// The blocks of `input` separated by blank lines, with the line each starts on.
+pub fn blocks(input: &str) -> impl Iterator<Item = (usize, &str)> {
+  let mut line = 1;
+
+  input.split("\n\n").filter_map(move |block| {
+    let start = line + block.len() - block.trim_start_matches('\n').len();
+    line += block.matches('\n').count() + 2;
+
+    let block = block.trim_matches('\n');
+
+    if block.is_empty() {
+      None
+    } else {
+      Some((start, block))
+    }
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_normalize() {
+    assert_eq!(normalize("\u{feff}+1\r\n-2\r\n\r\n"), "+1\n-2");
+    assert_eq!(normalize("dabAcCaCBAcCcaDA \n"), "dabAcCaCBAcCcaDA");
+    assert_eq!(normalize("+1 \n-2\t\r\n\n+3"), "+1\n-2\n\n+3");
+    assert!(matches!(normalize("0 1 5\n2"), Cow::Borrowed(_)));
+  }
+
+  #[test]
+  fn test_lines() {
+    assert_eq!(lines("a\n\nb\r\nc").collect::<Vec<_>>(), vec![(1, "a"), (3, "b"), (4, "c")]);
+  }
+
+  #[test]
+  fn test_tokens() {
+    let tokens: Vec<_> = tokens("2 3  0\n\n\t10 11").map(|token| (token.line, token.offset, token.text)).collect();
+
+    assert_eq!(tokens, vec![(1, 0, "2"), (1, 2, "3"), (1, 5, "0"), (3, 1, "10"), (3, 4, "11")]);
+  }
+
+  #[test]
+  fn test_blocks() {
+    assert_eq!(
+      blocks("a\nb\n\nc\n\n\n\nd\ne").collect::<Vec<_>>(),
+      vec![(1, "a\nb"), (4, "c"), (8, "d\ne")]
+    );
+  }
+}
@@ -0,0 +1,191 @@
+use report::{format_bytes, format_duration, Record};
+use serde_json;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::{Duration, SystemTime};
+
+/// How often the watched files are checked for changes.
+pub const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+#[derive(Debug)]
+pub enum WatchError {
+  Cargo(io::Error),
+  BuildFailed,
+  UnexpectedOutput(String),
+}
+
+impl fmt::Display for WatchError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      WatchError::Cargo(error) => write!(f, "could not run cargo: {}", error),
+      WatchError::BuildFailed => write!(f, "the build failed"),
+      WatchError::UnexpectedOutput(line) => write!(f, "could not understand the runner output {:?}", line),
+    }
+  }
+}
+
+/// The modification times of every file under the watched paths.
+///
+/// Paths that don't exist are skipped, so creating or removing a file counts
+/// as a change too.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Snapshot(BTreeMap<PathBuf, SystemTime>);
+
+impl Snapshot {
+  pub fn take(paths: &[PathBuf]) -> Snapshot {
+    let mut files = BTreeMap::new();
+
+    for path in paths {
+      visit(path, &mut files);
+    }
+
+    Snapshot(files)
+  }
+}
+
+fn visit(path: &Path, files: &mut BTreeMap<PathBuf, SystemTime>) {
+  match fs::metadata(path) {
+    Ok(ref metadata) if metadata.is_dir() => {
+      for entry in fs::read_dir(path).into_iter().flatten().flatten() {
+        visit(&entry.path(), files);
+      }
+    }
+    Ok(metadata) => {
+      files.insert(path.to_path_buf(), metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH));
+    }
+    Err(_) => {}
+  }
+}
+
+/// Rebuilds the runner from `manifest` and runs it with `args`, reading back
+/// its JSON records.
+///
+/// Compiler errors and anything the solutions print to stderr go straight to
+/// the terminal.
+pub fn rebuild_and_run(manifest: &Path, args: &[String]) -> Result<Vec<Record>, WatchError> {
+  let mut command = Command::new(env!("CARGO"));
+  command.args(["run", "--quiet", "--manifest-path"]).arg(manifest);
+
+  if !cfg!(debug_assertions) {
+    command.arg("--release");
+  }
+
+  let output = command
+    .arg("--")
+    .args(["--format", "json"])
+    .args(args)
+    .stderr(Stdio::inherit())
+    .output()
+    .map_err(WatchError::Cargo)?;
+
+  let records = String::from_utf8_lossy(&output.stdout)
+    .lines()
+    .map(|line| serde_json::from_str(line).map_err(|_| WatchError::UnexpectedOutput(line.to_string())))
+    .collect::<Result<Vec<Record>, _>>()?;
+
+  if records.is_empty() && !output.status.success() {
+    return Err(WatchError::BuildFailed);
+  }
+
+  Ok(records)
+}
+
+/// Describes `record`, pointing out when its answer differs from `previous`.
+pub fn describe(record: &Record, previous: Option<&Record>) -> String {
+  let label = format!("Day {} {}", record.day, record.part);
+
+  let answer = match (&record.answer, &record.error) {
+    (Some(answer), _) => answer,
+    (None, error) => {
+      return format!(
+        "{}: {}: {}",
+        label,
+        record.status,
+        error.as_deref().unwrap_or("no answer")
+      )
+    }
+  };
+
+  let mut timings = vec![];
+  if let Some(parse_ns) = record.parse_ns {
+    timings.push(format!("parse {}", format_duration(Duration::from_nanos(parse_ns))));
+  }
+  if let Some(solve_ns) = record.solve_ns {
+    timings.push(format!("solve {}", format_duration(Duration::from_nanos(solve_ns))));
+  }
+  if let Some(allocations) = record.allocations {
+    timings.push(format!(
+      "{} allocations, {} peak",
+      allocations.count,
+      format_bytes(allocations.peak)
+    ));
+  }
+
+  let mut description = format!("{}: {} ({})", label, answer, timings.join(", "));
+
+  match previous.and_then(|previous| previous.answer.as_ref()) {
+    Some(previous) if previous != answer => description += &format!("\n  changed, was {}", previous),
+    _ => {}
+  }
+
+  description
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use report::Status;
+  use std::env;
+
+  fn record(answer: Option<&str>, error: Option<&str>) -> Record {
+    Record {
+      year: 2018,
+      day: 7,
+      part: "b".to_string(),
+      status: if answer.is_some() { Status::Ok } else { Status::Failed },
+      answer: answer.map(str::to_string),
+      parse_ns: Some(250_000),
+      solve_ns: Some(1_500_000),
+      error: error.map(str::to_string),
+      allocations: None,
+    }
+  }
+
+  #[test]
+  fn test_snapshot() {
+    let dir = env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+    fs::create_dir_all(dir.join("day_07")).unwrap();
+    fs::write(dir.join("day_07/mod.rs"), "").unwrap();
+    let paths = [dir.join("day_07"), dir.join("day_07.input")];
+
+    let before = Snapshot::take(&paths);
+    let unchanged = Snapshot::take(&paths);
+    fs::write(dir.join("day_07.input"), "").unwrap();
+    let created = Snapshot::take(&paths);
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(before.0.len(), 1);
+    assert_eq!(before, unchanged);
+    assert_ne!(before, created);
+  }
+
+  #[test]
+  fn test_describe() {
+    let answer = record(Some("1040"), None);
+
+    assert_eq!(describe(&answer, None), "Day 7 b: 1040 (parse 250.0 µs, solve 1.5 ms)");
+    assert_eq!(describe(&answer, Some(&answer)), describe(&answer, None));
+    assert_eq!(
+      describe(&answer, Some(&record(Some("1041"), None))),
+      "Day 7 b: 1040 (parse 250.0 µs, solve 1.5 ms)\n  changed, was 1041"
+    );
+    assert_eq!(
+      describe(&record(None, Some("could not read a.input")), Some(&answer)),
+      "Day 7 b: failed: could not read a.input"
+    );
+  }
+}
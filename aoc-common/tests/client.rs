@@ -0,0 +1,140 @@
+extern crate aoc_common;
+
+use aoc_common::answers::Answers;
+use aoc_common::client::{fetch_input, Client, ClientError, Config, Fetched, Submission, Verdict};
+use aoc_common::solution::Part;
+use aoc_common::submit::{submit, Guesses, Refusal, SubmitError};
+use std::env;
+use std::fs;
+use std::io::prelude::*;
+use std::io::BufReader;
+use std::net::TcpListener;
+use std::path::PathBuf;
+use std::thread;
+
+/// Serves `status` and `body` to a single request and hands back the request.
+fn stub_server(status: &str, body: &str) -> (String, thread::JoinHandle<String>) {
+  let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+  let base_url = format!("http://{}", listener.local_addr().unwrap());
+  let response = format!(
+    "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+    status,
+    body.len(),
+    body
+  );
+
+  let server = thread::spawn(move || {
+    let (mut stream, _) = listener.accept().unwrap();
+    let mut reader = BufReader::new(stream.try_clone().unwrap());
+    let mut request = String::new();
+    let mut content_length = 0;
+
+    loop {
+      let mut line = String::new();
+      reader.read_line(&mut line).unwrap();
+
+      if line.trim_end().is_empty() {
+        break;
+      }
+
+      if let Some(length) = line.to_lowercase().strip_prefix("content-length: ") {
+        content_length = length.trim().parse().unwrap();
+      }
+
+      request += line.trim_end();
+      request += "\n";
+    }
+
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body).unwrap();
+    request += &String::from_utf8(body).unwrap();
+
+    stream.write_all(response.as_bytes()).unwrap();
+    request
+  });
+
+  (base_url, server)
+}
+
+fn client(base_url: &str) -> Client {
+  Client::new(Config {
+    session: Some("53c7e7".to_string()),
+    base_url: base_url.to_string(),
+    user_agent: "aoc-test".to_string(),
+  })
+}
+
+fn temp_path(name: &str) -> PathBuf {
+  env::temp_dir()
+    .join(format!("aoc-client-{}-{}", name, std::process::id()))
+    .join("day_10")
+    .join("a.input")
+}
+
+#[test]
+fn test_fetch_input_once() {
+  let (base_url, server) = stub_server("200 OK", "1 2 3\n");
+  let path = temp_path("once");
+  let client = client(&base_url);
+
+  let first = fetch_input(&client, &path, 2018, 10).unwrap();
+  let request = server.join().unwrap().to_lowercase();
+  // The stub only answers once, so a second download would fail.
+  let second = fetch_input(&client, &path, 2018, 10).unwrap();
+  let contents = fs::read_to_string(&path).unwrap();
+  fs::remove_dir_all(path.parent().unwrap().parent().unwrap()).unwrap();
+
+  assert_eq!((first, second), (Fetched::Downloaded, Fetched::Cached));
+  assert_eq!(contents, "1 2 3\n");
+  assert!(request.starts_with("get /2018/day/10/input http/1.1\n"));
+  assert!(request.contains("cookie: session=53c7e7\n"));
+  assert!(request.contains("user-agent: aoc-test\n"));
+}
+
+#[test]
+fn test_fetch_input_error() {
+  let (base_url, server) = stub_server("404 Not Found", "Not found");
+  let path = temp_path("error");
+
+  let error = fetch_input(&client(&base_url), &path, 2018, 10).unwrap_err();
+  server.join().unwrap();
+
+  match error {
+    ClientError::Status(_, status) => assert_eq!(status, 404),
+    error => panic!("unexpected error: {}", error),
+  }
+  assert!(!path.exists());
+}
+
+#[test]
+fn test_submit_records_guesses() {
+  let dir = temp_path("submit").parent().unwrap().to_path_buf();
+  fs::create_dir_all(&dir).unwrap();
+  let mut answers = Answers::load(&dir.join("answers.toml")).unwrap();
+  let mut guesses = Guesses::load(&dir.join("guesses.json")).unwrap();
+
+  let (base_url, server) = stub_server("200 OK", "<p>That's not the right answer; your answer is too high.</p>");
+  let too_high = submit(&client(&base_url), &mut answers, &mut guesses, 2018, 10, Part::B, "150");
+  let request = server.join().unwrap();
+  // Ruled out by the previous guess, so this never reaches the (stopped) server.
+  let refused = submit(&client(&base_url), &mut answers, &mut guesses, 2018, 10, Part::B, "200");
+
+  let (base_url, server) = stub_server("200 OK", "<p>That's the right answer!</p>");
+  let correct = submit(&client(&base_url), &mut answers, &mut guesses, 2018, 10, Part::B, "120");
+  server.join().unwrap();
+
+  let recorded = Answers::load(&dir.join("answers.toml")).unwrap();
+  let guessed = Guesses::load(&dir.join("guesses.json")).unwrap();
+  fs::remove_dir_all(dir.parent().unwrap()).unwrap();
+
+  assert_eq!(too_high.unwrap(), Submission::Judged(Verdict::TooHigh));
+  assert!(request.starts_with("POST /2018/day/10/answer HTTP/1.1\n"));
+  assert!(request.ends_with("\nlevel=2&answer=150"));
+  match refused {
+    Err(SubmitError::Refused(refusal)) => assert_eq!(refusal, Refusal::NotBelow("150".to_string())),
+    result => panic!("expected a refusal, got {:?}", result),
+  }
+  assert_eq!(correct.unwrap(), Submission::Judged(Verdict::Correct));
+  assert_eq!(recorded.get(2018, 10, Part::B), Some("120"));
+  assert_eq!(guessed.get(2018, 10, Part::B).count(), 2);
+}
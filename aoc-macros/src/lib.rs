@@ -0,0 +1,185 @@
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::{parse_macro_input, Error, Expr, ItemImpl, LitInt, LitStr, Token};
+
+/// Registers a `Solution` impl in the solution registry.
+///
+/// `#[aoc(day = 7)]` registers both parts of day 7, `#[aoc(day = 7, part = "b")]`
+/// only part b, for the year in the `YEAR` constant at the root of the crate.
+/// Registering the same day and part twice fails to build.
+#[proc_macro_attribute]
+pub fn aoc(attr: TokenStream, item: TokenStream) -> TokenStream {
+  let mut day = None;
+  let mut parts = vec![];
+
+  let attr_parser = syn::meta::parser(|meta| {
+    if meta.path.is_ident("day") {
+      let lit: LitInt = meta.value()?.parse()?;
+      let value: u32 = lit.base10_parse()?;
+
+      if !(1..=25).contains(&value) {
+        return Err(Error::new(lit.span(), "day must be between 1 and 25"));
+      }
+
+      day = Some(value);
+      Ok(())
+    } else if meta.path.is_ident("part") {
+      let lit: LitStr = meta.value()?.parse()?;
+
+      match lit.value().as_str() {
+        "a" | "b" => {
+          parts.push(lit.value());
+          Ok(())
+        }
+        _ => Err(Error::new(lit.span(), "part must be \"a\" or \"b\"")),
+      }
+    } else {
+      Err(meta.error("expected `day` or `part`"))
+    }
+  });
+
+  parse_macro_input!(attr with attr_parser);
+  let item = parse_macro_input!(item as ItemImpl);
+
+  let day = match day {
+    Some(day) => day,
+    None => {
+      return Error::new(Span::call_site(), "missing `day = N`")
+        .to_compile_error()
+        .into()
+    }
+  };
+
+  if parts.is_empty() {
+    parts = vec!["a".to_string(), "b".to_string()];
+  }
+
+  let self_ty = &item.self_ty;
+  // Year crates are named after their year, so the crate keeps the symbols apart.
+  let krate = std::env::var("CARGO_CRATE_NAME").unwrap_or_default();
+  let variants: Vec<_> = parts
+    .iter()
+    .map(|part| syn::Ident::new(&part.to_uppercase(), Span::call_site()))
+    .collect();
+  // Two registrations of the same day and part export the same symbol.
+  let symbols = parts.iter().map(|part| {
+    let symbol = format!("aoc_solution_{}_day_{:02}_{}", krate, day, part);
+
+    quote! {
+      const _: () = {
+        #[export_name = #symbol]
+        #[used]
+        static REGISTERED: u8 = 0;
+      };
+    }
+  });
+
+  quote!(
+    #item
+    #(#symbols)*
+
+    ::aoc_common::inventory::submit! {
+      ::aoc_common::registry::Registration {
+        year: crate::YEAR,
+        day: #day,
+        parts: &[#(::aoc_common::solution::Part::#variants),*],
+        solve: ::aoc_common::solution::solve_each::<#self_ty>,
+      }
+    }
+  )
+  .into()
+}
+
+struct ScanInput {
+  pattern: LitStr,
+  input: Expr,
+}
+
+impl Parse for ScanInput {
+  fn parse(stream: ParseStream) -> syn::Result<Self> {
+    let pattern = stream.parse()?;
+    stream.parse::<Token![,]>()?;
+    let input = stream.parse()?;
+    stream.parse::<Option<Token![,]>>()?;
+
+    Ok(ScanInput { pattern, input })
+  }
+}
+
+/// Splits a pattern into the text around its fields and how it reads in errors,
+/// where `{name}` reads as `<name>`.
+fn split_pattern(pattern: &str) -> Result<(Vec<String>, String), &'static str> {
+  let mut literals = vec![String::new()];
+  let mut description = String::new();
+  let mut chars = pattern.chars().peekable();
+
+  while let Some(c) = chars.next() {
+    match c {
+      '{' if chars.peek() == Some(&'{') => {
+        chars.next();
+        literals.last_mut().unwrap().push('{');
+        description.push('{');
+      }
+      '}' if chars.peek() == Some(&'}') => {
+        chars.next();
+        literals.last_mut().unwrap().push('}');
+        description.push('}');
+      }
+      '{' => {
+        let mut name = String::new();
+
+        loop {
+          match chars.next() {
+            Some('}') => break,
+            Some(c) if c.is_alphanumeric() || c == '_' => name.push(c),
+            _ => return Err("expected `{}` or `{name}`, or `{{` for a literal `{`"),
+          }
+        }
+
+        if literals.len() > 1 && literals.last().unwrap().is_empty() {
+          return Err("fields must be separated by some text");
+        }
+
+        literals.push(String::new());
+        description.push_str(&format!("<{}>", if name.is_empty() { "value" } else { &name }));
+      }
+      '}' => return Err("unmatched `}`, use `}}` for a literal `}`"),
+      c => {
+        literals.last_mut().unwrap().push(c);
+        description.push(c);
+      }
+    }
+  }
+
+  Ok((literals, description))
+}
+
+/// Matches a line against a pattern and parses its fields.
+///
+/// `scan!("#{id} @ {left},{top}: {width}x{height}", row)` is a
+/// `Result<(A, B, C, D, E), ScanError>` of whatever types the fields are parsed
+/// as, or of just the value when the pattern has a single field. Fields can be
+/// named to describe them in errors, and `{{` and `}}` stand for `{` and `}`.
+#[proc_macro]
+pub fn scan(input: TokenStream) -> TokenStream {
+  let ScanInput { pattern, input } = parse_macro_input!(input as ScanInput);
+
+  let (literals, description) = match split_pattern(&pattern.value()) {
+    Ok(split) => split,
+    Err(message) => return Error::new(pattern.span(), message).to_compile_error().into(),
+  };
+
+  let fields = (0..literals.len() - 1).map(|i| quote!(::aoc_common::scan::field(fields[#i])));
+  let parse = match literals.len() - 1 {
+    0 => quote!(map(|_| ())),
+    1 => quote!(and_then(|fields| #(#fields)*)),
+    _ => quote!(and_then(|fields| Ok((#(#fields?),*)))),
+  };
+
+  quote!(::aoc_common::scan::fields(&[#(#literals),*], #description, #input).#parse)
+  .into()
+}
@@ -0,0 +1,863 @@
+extern crate aoc_2018;
+extern crate aoc_common;
+
+use aoc_common::answers::{Answers, Check};
+use aoc_common::client::{self, Client, Config, Fetched, Submission, Verdict};
+use aoc_common::crypt::{self, Key};
+use aoc_common::input::{Inputs, Source};
+use aoc_common::report::{Failure, Record, Row, Status};
+use aoc_common::runner::{self, Job};
+use aoc_common::solution::{Part, Solve};
+use aoc_common::submit::{self, Guesses};
+use aoc_common::watch::{self, Snapshot};
+use aoc_common::{bench, examples, logging, registry, report, scaffold};
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt;
+use std::path::Path;
+use std::process;
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+/// The year crates, with where their days and inputs live.
+const YEARS: [(u32, &str); 1] = [(aoc_2018::YEAR, aoc_2018::SOURCE_DIR)];
+
+/// Environment variable selecting the year when --year is not given.
+const YEAR_VAR: &str = "AOC_YEAR";
+
+// Files shared by all years are kept next to the workspace manifest.
+const MANIFEST: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml");
+const DEFAULT_ANSWERS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/answers.toml");
+const DEFAULT_GUESSES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/guesses.json");
+/// Config file with `session`, `base_url` and `user_agent` keys, all optional.
+const DEFAULT_CONFIG: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/aoc.toml");
+/// The key is kept out of the repository.
+const DEFAULT_KEY: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.key");
+
+const USAGE: &str = "Usage: aoc [options] <day> [a|b]
+       aoc [options] all
+       aoc [options] verify [<day> [a|b]]
+       aoc [options] record [<day> [a|b]]
+       aoc [options] bench <day> [a|b]
+       aoc [options] watch <day> [a|b]
+       aoc new <day>
+       aoc examples <day> <page.html> [a|b]
+       aoc [options] fetch <year> <day>
+       aoc [options] submit <day> <a|b> [<answer>]
+       aoc [options] encrypt [<day>]
+       aoc [options] decrypt [<day>]
+
+Commands:
+  verify           Compare answers with the recorded answers
+  record           Record the current answers as the correct ones
+  bench            Time parsing and solving over many runs
+  watch            Rebuild and rerun whenever src/day_NN or its input changes,
+                   pointing out answers that changed since the previous run
+  new              Create src/day_NN in the year's crate from the templates and declare it in its lib.rs
+  examples         Save the examples of a saved puzzle page to src/day_NN/examples/
+                   and test a part (default a) against the answers given after them
+  fetch            Download a day's input into the inputs directory, unless it is there already
+  submit           Submit an answer, or the computed one, unless earlier guesses rule it out
+  encrypt          Encrypt day_NN/a.input to day_NN/a.input.enc, generating a key if there is none
+  decrypt          Decrypt day_NN/a.input.enc back to day_NN/a.input
+
+Options:
+  --year <year>    Run the solutions of <year> (default: the latest year)
+  --input <path>   Read the input from <path>, or from stdin if <path> is \"-\"
+  --inputs <dir>   Look up inputs as <dir>/day_NN/a.input
+  --answers <path> Read and record answers in <path>
+  --format <fmt>   Print results as text (the default), markdown or json, one object per line
+  --markdown       Same as --format markdown
+  --jobs <n>       Run up to <n> days at once (default: one per CPU)
+  --serial         Run one day at a time, e.g. for clean timings
+  --timeout <secs> Give up on a part after <secs> seconds, the first with its parse (default 300, 0 for never)
+  --runs <n>       Number of timed bench runs (default 20)
+  --warmup <n>     Number of untimed bench runs before timing (default 3)
+  --save <path>    Save bench results as JSON to <path>
+  --baseline <path>
+                   Compare bench results with the JSON saved in <path>
+  --config <path>  Read the session, base_url and user_agent settings from <path>
+  --guesses <path> Read and record submitted answers in <path>
+  --key <path>     Encrypt and decrypt inputs with the key in <path>
+  -v, --verbose    Log parse and solve times to stderr, -vv and -vvv for the solutions' debug output
+  -q, --quiet      Only log errors
+
+Environment:
+  AOC_YEAR         Year used when --year is not given
+  AOC_INPUT        Input path used when --input is not given
+  AOC_INPUTS_DIR   Inputs directory used when --inputs is not given
+  AOC_SESSION      Session token, overriding the config file
+  AOC_BASE_URL     Puzzle site URL, overriding the config file
+  AOC_USER_AGENT   User-Agent header, overriding the config file
+  AOC_INPUT_KEY    Input key path used when --key is not given";
+
+#[derive(Debug, Default, PartialEq)]
+struct Options {
+  year: Option<String>,
+  input: Option<String>,
+  inputs_dir: Option<String>,
+  answers: Option<String>,
+  format: Option<String>,
+  markdown: bool,
+  jobs: Option<String>,
+  serial: bool,
+  timeout: Option<String>,
+  runs: Option<String>,
+  warmup: Option<String>,
+  save: Option<String>,
+  baseline: Option<String>,
+  config: Option<String>,
+  guesses: Option<String>,
+  key: Option<String>,
+  verbosity: i32,
+}
+
+fn parse_options(args: &[String]) -> Result<(Options, Vec<String>), String> {
+  let mut options = Options::default();
+  let mut positional = vec![];
+  let mut args = args.iter();
+
+  while let Some(arg) = args.next() {
+    let value = match arg.as_str() {
+      "--year" => &mut options.year,
+      "--input" => &mut options.input,
+      "--format" => &mut options.format,
+      "--inputs" => &mut options.inputs_dir,
+      "--answers" => &mut options.answers,
+      "--jobs" => &mut options.jobs,
+      "--timeout" => &mut options.timeout,
+      "--runs" => &mut options.runs,
+      "--warmup" => &mut options.warmup,
+      "--save" => &mut options.save,
+      "--baseline" => &mut options.baseline,
+      "--config" => &mut options.config,
+      "--guesses" => &mut options.guesses,
+      "--key" => &mut options.key,
+      "--markdown" => {
+        options.markdown = true;
+        continue;
+      }
+      "--serial" => {
+        options.serial = true;
+        continue;
+      }
+      "--verbose" => {
+        options.verbosity += 1;
+        continue;
+      }
+      "-q" | "--quiet" => {
+        options.verbosity -= 1;
+        continue;
+      }
+      flags if flags.len() > 1 && flags.starts_with('-') && flags[1..].chars().all(|flag| flag == 'v') => {
+        options.verbosity += flags.len() as i32 - 1;
+        continue;
+      }
+      _ => {
+        positional.push(arg.clone());
+        continue;
+      }
+    };
+
+    *value = Some(
+      args
+        .next()
+        .ok_or_else(|| format!("{} expects a value", arg))?
+        .clone(),
+    );
+  }
+
+  Ok((options, positional))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Format {
+  Text,
+  Markdown,
+  Json,
+}
+
+fn parse_format(options: &Options) -> Result<Format, String> {
+  match options.format.as_deref() {
+    None if options.markdown => Ok(Format::Markdown),
+    None | Some("text") => Ok(Format::Text),
+    Some("markdown") => Ok(Format::Markdown),
+    Some("json") => Ok(Format::Json),
+    Some(format) => Err(format!("format must be text, markdown or json, got \"{}\"", format)),
+  }
+}
+
+fn parse_day_number(arg: &str) -> Result<u32, String> {
+  let day: u32 = arg
+    .parse()
+    .map_err(|_| format!("\"{}\" is not a day number", arg))?;
+
+  if !(1..=25).contains(&day) {
+    return Err(format!("day must be between 1 and 25, got {}", day));
+  }
+
+  Ok(day)
+}
+
+fn source_dir(year: u32) -> Result<&'static Path, String> {
+  YEARS
+    .iter()
+    .find(|&&(y, _)| y == year)
+    .map(|&(_, dir)| Path::new(dir))
+    .ok_or_else(|| format!("there are no {} solutions", year))
+}
+
+fn parse_year(arg: &str) -> Result<u32, String> {
+  let year = arg.parse().map_err(|_| format!("\"{}\" is not a year", arg))?;
+
+  source_dir(year)?;
+
+  Ok(year)
+}
+
+fn select_year(options: &Options) -> Result<u32, String> {
+  match options.year.clone().or_else(|| env::var(YEAR_VAR).ok()) {
+    Some(year) => parse_year(&year),
+    None => Ok(YEARS.iter().map(|&(year, _)| year).max().expect("There is at least one year")),
+  }
+}
+
+fn parse_day(year: u32, arg: &str) -> Result<Vec<(u32, Solve, Vec<Part>)>, String> {
+  let day = parse_day_number(arg)?;
+  let solutions = registry::day(year, day);
+
+  if solutions.is_empty() {
+    return Err(format!("day {} of {} is not implemented", day, year));
+  }
+
+  Ok(solutions)
+}
+
+fn select(year: u32, args: &[String]) -> Result<Vec<(u32, Solve, Vec<Part>)>, String> {
+  match args {
+    [] => Err("expected a day and an optional part".to_string()),
+    [all] if all == "all" => Ok(registry::days(year)),
+    [day] => parse_day(year, day),
+    [day, part] => {
+      let solutions = parse_day(year, day)?;
+      let part = part.parse()?;
+
+      match solutions.into_iter().find(|(_, _, parts)| parts.contains(&part)) {
+        Some((day, solve, _)) => Ok(vec![(day, solve, vec![part])]),
+        None => Err(format!("day {} part {} is not implemented", day, part)),
+      }
+    }
+    _ => Err(format!("unexpected argument \"{}\"", args[2])),
+  }
+}
+
+#[derive(Debug, PartialEq)]
+enum Command {
+  Run,
+  Verify,
+  Record,
+  Bench,
+  Watch,
+  New,
+  Fetch,
+  Submit,
+  Examples,
+  Encrypt,
+  Decrypt,
+}
+
+fn parse_command(args: &[String]) -> (Command, Vec<String>) {
+  let (command, rest) = match args.split_first() {
+    Some((command, rest)) if command == "verify" => (Command::Verify, rest),
+    Some((command, rest)) if command == "record" => (Command::Record, rest),
+    Some((command, rest)) if command == "encrypt" => (Command::Encrypt, rest),
+    Some((command, rest)) if command == "decrypt" => (Command::Decrypt, rest),
+    Some((command, rest)) if command == "bench" => return (Command::Bench, rest.to_vec()),
+    Some((command, rest)) if command == "watch" => return (Command::Watch, rest.to_vec()),
+    Some((command, rest)) if command == "new" => return (Command::New, rest.to_vec()),
+    Some((command, rest)) if command == "examples" => return (Command::Examples, rest.to_vec()),
+    Some((command, rest)) if command == "fetch" => return (Command::Fetch, rest.to_vec()),
+    Some((command, rest)) if command == "submit" => return (Command::Submit, rest.to_vec()),
+    _ => return (Command::Run, args.to_vec()),
+  };
+
+  if rest.is_empty() {
+    (command, vec!["all".to_string()])
+  } else {
+    (command, rest.to_vec())
+  }
+}
+
+fn fail(error: impl fmt::Display) -> ! {
+  eprintln!("error: {}", error);
+  process::exit(1);
+}
+
+fn usage_error(error: impl fmt::Display) -> ! {
+  eprintln!("error: {}\n\n{}", error, USAGE);
+  process::exit(1);
+}
+
+fn new_day(year: u32, args: &[String]) {
+  let day = match args {
+    [day] => parse_day_number(day).unwrap_or_else(|error| usage_error(error)),
+    _ => usage_error("new expects a single day"),
+  };
+
+  let created = scaffold::scaffold(source_dir(year).unwrap_or_else(|error| fail(error)), day).unwrap_or_else(|error| fail(error));
+
+  for path in created {
+    println!("Wrote {}", path.display());
+  }
+}
+
+fn save_examples(year: u32, args: &[String]) {
+  let (day, page, part) = match args {
+    [day, page] => (day, page, Ok(Part::A)),
+    [day, page, part] => (day, page, part.parse()),
+    _ => usage_error("examples expects a day, a saved puzzle page and an optional part"),
+  };
+  let day = parse_day_number(day).unwrap_or_else(|error| usage_error(error));
+  let part = part.unwrap_or_else(|error| usage_error(error));
+
+  let written = examples::write_examples(source_dir(year).unwrap_or_else(|error| fail(error)), day, Path::new(page), part)
+    .unwrap_or_else(|error| fail(error));
+
+  for path in written {
+    println!("Wrote {}", path.display());
+  }
+}
+
+fn crypt_inputs(command: &Command, inputs: &Inputs, solutions: Vec<(u32, Solve, Vec<Part>)>) {
+  let key = match Key::load(inputs.key()) {
+    Ok(key) => key,
+    Err(_) if *command == Command::Encrypt && !inputs.key().exists() => {
+      println!("Generated a new key in {}", inputs.key().display());
+      Key::generate(inputs.key()).unwrap_or_else(|error| fail(error))
+    }
+    Err(error) => fail(error),
+  };
+
+  let verb = if *command == Command::Encrypt { "encrypt" } else { "decrypt" };
+  let mut days: Vec<_> = solutions.into_iter().map(|(day, _, _)| day).collect();
+  days.dedup();
+
+  for day in days {
+    let path = inputs.path(day);
+    let written = match command {
+      Command::Encrypt if path.exists() => crypt::encrypt_file(&key, &path),
+      Command::Decrypt if crypt::encrypted_path(&path).exists() => {
+        crypt::decrypt_file(&key, &crypt::encrypted_path(&path))
+      }
+      _ => {
+        println!("Day {} has no input to {}", day, verb);
+        continue;
+      }
+    };
+
+    println!("Wrote {}", written.unwrap_or_else(|error| fail(error)).display());
+  }
+}
+
+fn client(options: &Options) -> Client {
+  let config_path = Path::new(options.config.as_deref().unwrap_or(DEFAULT_CONFIG));
+
+  Client::new(Config::load(config_path).unwrap_or_else(|error| fail(error)))
+}
+
+fn inputs(options: &Options, year: u32) -> Inputs {
+  Inputs::new(
+    options.input.as_deref(),
+    options.inputs_dir.as_ref().map(Path::new),
+    options.key.as_ref().map(Path::new),
+    source_dir(year).unwrap_or_else(|error| fail(error)),
+    Path::new(DEFAULT_KEY),
+  )
+}
+
+fn fetch(options: &Options, args: &[String]) {
+  let (year, day) = match args {
+    [year, day] => (
+      parse_year(year).unwrap_or_else(|error| usage_error(error)),
+      parse_day_number(day).unwrap_or_else(|error| usage_error(error)),
+    ),
+    _ => usage_error("fetch expects a year and a day"),
+  };
+
+  let path = inputs(options, year).path(day);
+
+  match client::fetch_input(&client(options), &path, year, day) {
+    Ok(Fetched::Downloaded) => println!("Wrote {}", path.display()),
+    Ok(Fetched::Cached) => println!("{} is already downloaded", path.display()),
+    Err(error) => fail(error),
+  }
+}
+
+fn submit_answer(options: &Options, year: u32, inputs: &Inputs, answers_path: &Path, args: &[String]) {
+  let answer = match args {
+    [_, _] => None,
+    [_, _, answer] => Some(answer.clone()),
+    _ => usage_error("submit expects a day, a part and an optional answer"),
+  };
+  let solutions = select(year, &args[..2]).unwrap_or_else(|error| usage_error(error));
+  let (day, part) = (solutions[0].0, solutions[0].2[0]);
+  let answer = answer.unwrap_or_else(|| run_or_fail(inputs, solutions, 1, timeout(options))[0].answer.to_string());
+
+  let guesses_path = Path::new(options.guesses.as_deref().unwrap_or(DEFAULT_GUESSES));
+  let mut answers = Answers::load(answers_path).unwrap_or_else(|error| fail(error));
+  let mut guesses = Guesses::load(guesses_path).unwrap_or_else(|error| fail(error));
+
+  println!("Submitting Day {} {}: {}", day, part, answer);
+
+  match submit::submit(&client(options), &mut answers, &mut guesses, year, day, part, &answer) {
+    Ok(Submission::Judged(Verdict::Correct)) => println!("Correct, recorded the answer"),
+    Ok(Submission::Judged(verdict)) => {
+      println!("Wrong answer, it is {}", verdict);
+      process::exit(1);
+    }
+    Ok(Submission::RateLimited(wait)) => fail(format!(
+      "answered too recently, try again in {}s",
+      wait.as_secs()
+    )),
+    Ok(Submission::WrongLevel) => fail("the part is either solved already or not unlocked yet"),
+    Err(error) => fail(error),
+  }
+}
+
+fn watch_day(options: &Options, year: u32, inputs: &Inputs, args: &[String]) {
+  let solutions = match args {
+    [_] | [_, _] => select(year, args).unwrap_or_else(|error| usage_error(error)),
+    _ => usage_error("watch expects a day and an optional part"),
+  };
+  let day = solutions[0].0;
+
+  let mut paths = vec![source_dir(year)
+    .unwrap_or_else(|error| fail(error))
+    .join(format!("day_{:02}", day))];
+  match inputs.source(day) {
+    Source::Stdin => usage_error("watch cannot read the input from stdin"),
+    Source::Path(path) | Source::Encrypted { path, .. } => paths.push(path),
+  }
+  paths.push(inputs.path(day));
+  paths.push(crypt::encrypted_path(&inputs.path(day)));
+
+  let mut forwarded = vec!["--year".to_string(), year.to_string()];
+  for (name, value) in [
+    ("--input", &options.input),
+    ("--inputs", &options.inputs_dir),
+    ("--key", &options.key),
+    ("--timeout", &options.timeout),
+  ] {
+    if let Some(value) = value {
+      forwarded.extend([name.to_string(), value.clone()]);
+    }
+  }
+  if options.verbosity != 0 {
+    let flag = if options.verbosity > 0 { "v" } else { "q" };
+    forwarded.push(format!("-{}", flag.repeat(options.verbosity.unsigned_abs() as usize)));
+  }
+  forwarded.extend(args.iter().cloned());
+
+  let mut previous: BTreeMap<(u32, String), Record> = BTreeMap::new();
+  let mut snapshot = Snapshot::take(&paths);
+
+  loop {
+    println!("Running Day {}...", day);
+
+    match watch::rebuild_and_run(Path::new(MANIFEST), &forwarded) {
+      Ok(records) => {
+        for record in records {
+          let key = (record.day, record.part.clone());
+
+          println!("{}", watch::describe(&record, previous.get(&key)));
+
+          if record.answer.is_some() {
+            previous.insert(key, record);
+          }
+        }
+      }
+      Err(error) => eprintln!("error: {}", error),
+    }
+
+    println!("Waiting for changes to {}", paths[0].display());
+
+    loop {
+      thread::sleep(watch::POLL_INTERVAL);
+
+      let current = Snapshot::take(&paths);
+      if current != snapshot {
+        snapshot = current;
+        break;
+      }
+    }
+  }
+}
+
+fn timeout(options: &Options) -> Option<Duration> {
+  match count(&options.timeout, "--timeout", runner::DEFAULT_TIMEOUT.as_secs() as usize) {
+    0 => None,
+    seconds => Some(Duration::from_secs(seconds as u64)),
+  }
+}
+
+fn workers(options: &Options) -> usize {
+  if options.serial {
+    return 1;
+  }
+
+  let cpus = thread::available_parallelism().map_or(1, |cpus| cpus.get());
+
+  count(&options.jobs, "--jobs", cpus).max(1)
+}
+
+/// Runs each day of `solutions` on its own, `workers` days at a time, so a day
+/// that panics or hangs doesn't take the other days down with it.
+fn run(
+  inputs: &Inputs,
+  solutions: Vec<(u32, Solve, Vec<Part>)>,
+  workers: usize,
+  timeout: Option<Duration>,
+) -> (Vec<Row>, Vec<Failure>) {
+  if inputs.is_explicit() && solutions.iter().any(|&(day, _, _)| day != solutions[0].0) {
+    fail("an explicit input can only be used with a single day");
+  }
+
+  let mut jobs: Vec<Job> = vec![];
+  let mut failures = vec![];
+
+  for (day, solve, parts) in solutions {
+    // Parts of a day registered on different impls read its input once.
+    let loaded = jobs.last().filter(|job| job.day == day).map(|job| Ok(job.input.clone()));
+    let input: Arc<str> = match loaded.unwrap_or_else(|| inputs.load(day).map(Arc::from)) {
+      Ok(input) => input,
+      Err(error) => {
+        failures.push(Failure {
+          day,
+          parts,
+          status: Status::Failed,
+          error: error.to_string(),
+        });
+        continue;
+      }
+    };
+
+    jobs.push(Job {
+      day,
+      parts,
+      solve,
+      input,
+    });
+  }
+
+  let mut rows: Vec<Row> = vec![];
+  let outcomes = runner::run_jobs(jobs.clone(), workers, timeout);
+
+  for (job, outcome) in jobs.into_iter().zip(outcomes) {
+    if let Some(solved) = outcome.solved {
+      rows.extend(Row::from_solved(job.day, solved));
+    }
+
+    for (part, failed) in outcome.failed {
+      let (status, error) = (failed.status(), failed.to_string());
+
+      // Parts that failed the same way, like on a parse error, share a line.
+      match failures.last_mut() {
+        Some(last) if last.day == job.day && last.status == status && last.error == error => last.parts.push(part),
+        _ => failures.push(Failure {
+          day: job.day,
+          parts: vec![part],
+          status,
+          error,
+        }),
+      }
+    }
+  }
+
+  failures.sort_by_key(|failure| failure.day);
+
+  (rows, failures)
+}
+
+/// Runs `solutions`, failing on the first part that could not be solved.
+fn run_or_fail(
+  inputs: &Inputs,
+  solutions: Vec<(u32, Solve, Vec<Part>)>,
+  workers: usize,
+  timeout: Option<Duration>,
+) -> Vec<Row> {
+  let (rows, failures) = run(inputs, solutions, workers, timeout);
+
+  if let Some(failure) = failures.into_iter().next() {
+    fail(failure.error);
+  }
+
+  rows
+}
+
+fn verify(year: u32, rows: &[Row], answers: &Answers) -> bool {
+  let mut all_correct = true;
+
+  for row in rows {
+    let answer = row.answer.to_string();
+
+    match answers.check(year, row.day, row.part, &answer) {
+      Check::Correct => println!("ok    Day {} {}: {}", row.day, row.part, answer),
+      Check::Unrecorded => println!("new   Day {} {}: {}", row.day, row.part, answer),
+      Check::Wrong { expected } => {
+        all_correct = false;
+        println!("FAIL  Day {} {}", row.day, row.part);
+        println!("      - expected: {}", expected);
+        println!("      + actual:   {}", answer);
+      }
+    }
+  }
+
+  all_correct
+}
+
+fn count(option: &Option<String>, name: &str, default: usize) -> usize {
+  option.as_ref().map_or(default, |value| {
+    value
+      .parse()
+      .unwrap_or_else(|_| fail(format!("{} expects a number, got \"{}\"", name, value)))
+  })
+}
+
+fn run_bench(options: &Options, year: u32, inputs: &Inputs, solutions: Vec<(u32, Solve, Vec<Part>)>) {
+  let runs = count(&options.runs, "--runs", 20).max(1);
+  let warmup = count(&options.warmup, "--warmup", 3);
+  let baseline = options
+    .baseline
+    .as_ref()
+    .map(|path| bench::load(Path::new(path)).unwrap_or_else(|error| fail(error)))
+    .unwrap_or_default();
+  let mut benches = vec![];
+
+  for (day, solve, parts) in solutions {
+    let input = inputs.load(day).unwrap_or_else(|error| fail(error));
+
+    for part in parts {
+      let bench = bench::bench(year, day, solve, &input, part, warmup, runs).unwrap_or_else(|error| fail(error));
+      let baseline = baseline
+        .iter()
+        .find(|baseline| (baseline.year, baseline.day, &baseline.part) == (year, day, &bench.part));
+
+      print!("{}", bench::format(&bench, baseline));
+      benches.push(bench);
+    }
+  }
+
+  if let Some(path) = &options.save {
+    bench::save(Path::new(path), &benches).unwrap_or_else(|error| fail(error));
+  }
+}
+
+fn main() {
+  let args: Vec<String> = env::args().skip(1).collect();
+
+  let (options, args) = parse_options(&args).unwrap_or_else(|error| usage_error(error));
+  let (command, args) = parse_command(&args);
+  let format = parse_format(&options).unwrap_or_else(|error| usage_error(error));
+
+  logging::init(logging::level(options.verbosity));
+
+  if command == Command::Fetch {
+    return fetch(&options, &args);
+  }
+
+  let year = select_year(&options).unwrap_or_else(|error| usage_error(error));
+
+  if command == Command::New {
+    return new_day(year, &args);
+  }
+
+  if command == Command::Examples {
+    return save_examples(year, &args);
+  }
+
+  let inputs = inputs(&options, year);
+
+  let answers_path = Path::new(options.answers.as_deref().unwrap_or(DEFAULT_ANSWERS));
+
+  if command == Command::Submit {
+    return submit_answer(&options, year, &inputs, answers_path, &args);
+  }
+
+  if command == Command::Watch {
+    return watch_day(&options, year, &inputs, &args);
+  }
+
+  let run_all = args == ["all"];
+  let solutions = select(year, &args).unwrap_or_else(|error| usage_error(error));
+
+  if command == Command::Bench {
+    return run_bench(&options, year, &inputs, solutions);
+  }
+
+  if command == Command::Encrypt || command == Command::Decrypt {
+    return crypt_inputs(&command, &inputs, solutions);
+  }
+
+  if command == Command::Record {
+    let rows = run_or_fail(&inputs, solutions, workers(&options), timeout(&options));
+    let mut answers = Answers::load(answers_path).unwrap_or_else(|error| fail(error));
+
+    for row in &rows {
+      answers.insert(year, row.day, row.part, row.answer.to_string());
+      println!("Recorded Day {} {}: {}", row.day, row.part, row.answer);
+    }
+
+    return answers.save().unwrap_or_else(|error| fail(error));
+  }
+
+  let (rows, failures) = run(&inputs, solutions, workers(&options), timeout(&options));
+
+  let all_correct = match command {
+    Command::Run if format == Format::Json => {
+      print!("{}", report::json(year, &rows, &failures));
+      true
+    }
+    Command::Verify => {
+      let answers = Answers::load(answers_path).unwrap_or_else(|error| fail(error));
+
+      verify(year, &rows, &answers)
+    }
+    Command::Record
+    | Command::Bench
+    | Command::Watch
+    | Command::New
+    | Command::Examples
+    | Command::Fetch
+    | Command::Submit
+    | Command::Encrypt
+    | Command::Decrypt => unreachable!(),
+    Command::Run if format == Format::Markdown => {
+      print!("{}", report::markdown(&rows));
+      true
+    }
+    Command::Run if run_all => {
+      print!("{}", report::table(&rows));
+      true
+    }
+    Command::Run => {
+      for row in rows {
+        println!("Day {} {}: {}", row.day, row.part, row.answer);
+      }
+      true
+    }
+  };
+
+  if format != Format::Json {
+    print!("{}", report::failures(&failures));
+  }
+
+  if !all_correct || !failures.is_empty() {
+    process::exit(1);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn args(args: &[&str]) -> Vec<String> {
+    args.iter().map(|arg| arg.to_string()).collect()
+  }
+
+  fn selected(args: &[String]) -> Vec<(u32, Part)> {
+    select(2018, args)
+      .unwrap()
+      .into_iter()
+      .flat_map(|(day, _, parts)| parts.into_iter().map(move |part| (day, part)))
+      .collect()
+  }
+
+  #[test]
+  fn test_select_day_and_part() {
+    assert_eq!(selected(&args(&["4", "b"])), vec![(4, Part::B)]);
+  }
+
+  #[test]
+  fn test_select_both_parts() {
+    assert_eq!(selected(&args(&["7"])), vec![(7, Part::A), (7, Part::B)]);
+  }
+
+  #[test]
+  fn test_select_all() {
+    assert_eq!(
+      selected(&args(&["all"])).len(),
+      registry::days(2018).iter().map(|(_, _, parts)| parts.len()).sum::<usize>()
+    );
+  }
+
+  #[test]
+  fn test_select_missing_day() {
+    assert_eq!(
+      select(2018, &args(&["6"])).err(),
+      Some("day 6 of 2018 is not implemented".to_string())
+    );
+  }
+
+  #[test]
+  fn test_years_are_listed() {
+    assert_eq!(registry::years(), YEARS.iter().map(|&(year, _)| year).collect::<Vec<_>>());
+    assert_eq!(parse_year("2018"), Ok(2018));
+    assert_eq!(parse_year("2017").err(), Some("there are no 2017 solutions".to_string()));
+  }
+
+  #[test]
+  fn test_parse_options() {
+    let (options, positional) = parse_options(&args(&["--input", "-", "4", "b"])).unwrap();
+
+    assert_eq!(options.input, Some("-".to_string()));
+    assert_eq!(positional, args(&["4", "b"]));
+    assert!(parse_options(&args(&["4", "--inputs"])).is_err());
+  }
+
+  #[test]
+  fn test_parse_verbosity() {
+    let verbosity = |arguments: &[&str]| parse_options(&args(arguments)).unwrap().0.verbosity;
+
+    assert_eq!(verbosity(&["7"]), 0);
+    assert_eq!(verbosity(&["-v", "7", "--verbose"]), 2);
+    assert_eq!(verbosity(&["-vvv"]), 3);
+    assert_eq!(verbosity(&["-q"]), -1);
+  }
+
+  #[test]
+  fn test_parse_format() {
+    let format = |arguments: &[&str]| parse_format(&parse_options(&args(arguments)).unwrap().0);
+
+    assert_eq!(format(&[]), Ok(Format::Text));
+    assert_eq!(format(&["--markdown"]), Ok(Format::Markdown));
+    assert_eq!(format(&["--format", "json"]), Ok(Format::Json));
+    assert!(format(&["--format", "xml"]).is_err());
+  }
+
+  #[test]
+  fn test_parse_command() {
+    assert_eq!(
+      parse_command(&args(&["verify"])),
+      (Command::Verify, args(&["all"]))
+    );
+    assert_eq!(
+      parse_command(&args(&["record", "9", "b"])),
+      (Command::Record, args(&["9", "b"]))
+    );
+    assert_eq!(parse_command(&args(&["new", "10"])), (Command::New, args(&["10"])));
+    assert_eq!(
+      parse_command(&args(&["fetch", "2018", "10"])),
+      (Command::Fetch, args(&["2018", "10"]))
+    );
+    assert_eq!(parse_command(&args(&["9"])), (Command::Run, args(&["9"])));
+  }
+
+  #[test]
+  fn test_select_invalid_input() {
+    assert!(select(2018, &args(&[])).is_err());
+    assert!(select(2018, &args(&["26"])).is_err());
+    assert!(select(2018, &args(&["x"])).is_err());
+    assert!(select(2018, &args(&["4", "c"])).is_err());
+  }
+}